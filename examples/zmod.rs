@@ -0,0 +1,24 @@
+use inertia_algebra::*;
+
+use inertia_generic::poly::*;
+use inertia_generic::zmod::*;
+
+fn main() {
+    let zn = Zmod::init(12u64);
+
+    let x = zn.new(-20);
+    println!("x = {}", x);
+
+    let y = zn.new(25);
+    println!("y = {}", y);
+    println!("x*y mod 12 = {}", &x * &y);
+    println!("y^-1 mod 12 = {}", y.inv().unwrap());
+    println!("x is a unit: {}", x.is_unit());
+
+    let znx = GenericPolyRing::init(&zn, "x");
+    let f = znx.new([1, 0, 1]);
+    let g = znx.new([3, 2, 1]);
+    println!("f = {}", &f);
+    println!("g = {}", &g);
+    println!("f*g = {}", f*g);
+}
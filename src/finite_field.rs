@@ -0,0 +1,580 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::mat::{charpoly_hessenberg, det_gaussian};
+use crate::poly::{GenericPoly, GenericPolyRing};
+use crate::quotient::{GenericPolyQuotient, GenericPolyQuotientRing};
+use crate::traits::{DetRing, DivisionRing, ExactDivisionRing, FiniteField, InvertibleRing};
+use crate::util::{factor_u128, ring_pow};
+
+mod ops;
+
+// the order of a finite field, which must fit in a u128
+pub(crate) fn field_order<F: FiniteField + ?Sized>(field: &F) -> u128 {
+    field.order().expect("Field order must fit in a u128.")
+}
+
+pub(crate) fn is_square<F: FiniteField + ?Sized>(field: &F, a: &Elem<F>) -> bool {
+    if a.is_zero() || field.characteristic() == 2 {
+        return true;
+    }
+    let q = field_order(field);
+    ring_pow(field, a, (q - 1)/2).is_one()
+}
+
+// Tonelli-Shanks in odd characteristic, inverse Frobenius in characteristic two
+pub(crate) fn sqrt<F: FiniteField + ?Sized>(field: &F, a: &Elem<F>) -> Option<Elem<F>> {
+    if a.is_zero() {
+        return Some(a.clone());
+    }
+    let q = field_order(field);
+    if field.characteristic() == 2 {
+        return Some(ring_pow(field, a, q/2));
+    }
+    if !is_square(field, a) {
+        return None;
+    }
+
+    // q - 1 = 2^s * t with t odd
+    let s = (q - 1).trailing_zeros();
+    let t = (q - 1) >> s;
+    let z = (2..).map(|i| field.element(i))
+        .find(|z| !is_square(field, z))
+        .unwrap();
+
+    let mut m = s;
+    let mut c = ring_pow(field, &z, t);
+    let mut x = ring_pow(field, a, (t + 1)/2);
+    let mut b = ring_pow(field, a, t);
+    while !b.is_one() {
+        // the least i with b^(2^i) = 1
+        let mut i = 0;
+        let mut d = b.clone();
+        while !d.is_one() {
+            let e = d.clone();
+            d.mul_assign(&e);
+            i += 1;
+        }
+        let mut g = c;
+        for _ in 0..m - i - 1 {
+            let e = g.clone();
+            g.mul_assign(&e);
+        }
+        x.mul_assign(&g);
+        c = g.clone();
+        c.mul_assign(&g);
+        b.mul_assign(&c);
+        m = i;
+    }
+    Some(x)
+}
+
+pub(crate) fn multiplicative_order<F: FiniteField + ?Sized>(
+    field: &F,
+    a: &Elem<F>
+) -> Option<u128> {
+    if a.is_zero() {
+        return None;
+    }
+    let mut ord = field_order(field) - 1;
+    for (r, _) in factor_u128(ord) {
+        while ord % r == 0 && ring_pow(field, a, ord/r).is_one() {
+            ord /= r;
+        }
+    }
+    Some(ord)
+}
+
+///////////////////////////////////////////////////////////////////
+// GenericFiniteField<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct GenericFiniteFieldCtx<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) quotient: GenericPolyQuotientRing<T>,
+}
+
+/// The finite field `F[x]/(f)` for a finite field `F`, typically a prime
+/// field, and an irreducible polynomial `f`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericFiniteField<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericFiniteFieldCtx<T>>,
+}
+
+impl<T: FiniteField> GenericFiniteField<T> {
+    /// The extension of the base field by a root of `modulus`. Panics if
+    /// `modulus` is not irreducible.
+    pub fn init(modulus: &GenericPoly<T>) -> Self {
+        assert!(modulus.is_irreducible(), "Modulus must be irreducible.");
+        let quotient = GenericPolyQuotientRing::init(&modulus.parent(), modulus);
+        GenericFiniteField {
+            ctx: Rc::new(GenericFiniteFieldCtx { quotient })
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.quotient.base_ring()
+    }
+
+    #[inline]
+    pub fn poly_ring(&self) -> &GenericPolyRing<T> {
+        self.ctx.quotient.poly_ring()
+    }
+
+    /// The monic modulus `f`.
+    #[inline]
+    pub fn modulus(&self) -> &GenericPoly<T> {
+        self.ctx.quotient.modulus()
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.quotient.var()
+    }
+
+    /// The degree of the extension over the base field.
+    #[inline]
+    pub fn relative_degree(&self) -> usize {
+        self.ctx.quotient.degree()
+    }
+
+    /// The residue class of `poly`.
+    #[inline]
+    pub fn from_poly(&self, poly: GenericPoly<T>) -> GenericFiniteFieldElem<T> {
+        self.from_quotient(self.ctx.quotient.from_poly(poly))
+    }
+
+    #[inline]
+    fn from_quotient(&self, val: GenericPolyQuotient<T>) -> GenericFiniteFieldElem<T> {
+        GenericFiniteFieldElem {
+            ctx: Rc::clone(&self.ctx),
+            val,
+        }
+    }
+
+    /// The root `x` of the modulus.
+    #[inline]
+    pub fn gen(&self) -> GenericFiniteFieldElem<T> {
+        self.from_quotient(self.ctx.quotient.gen())
+    }
+
+    /// Iterate over all elements, in the order of `element`.
+    pub fn elements(&self) -> impl Iterator<Item = GenericFiniteFieldElem<T>> + '_ {
+        let base = self.base_ring();
+        let q = field_order(base);
+        // the base q digits of the index, least significant first
+        let mut digits = Some(vec![0u128; self.relative_degree()]);
+        std::iter::from_fn(move || {
+            let mut next = digits.take()?;
+            let coeffs: Vec<_> = next.iter().map(|&d| base.element(d)).collect();
+            if let Some(j) = next.iter().position(|&d| d + 1 < q) {
+                next[..j].iter_mut().for_each(|d| *d = 0);
+                next[j] += 1;
+                digits = Some(next);
+            }
+            Some(self.new(coeffs))
+        })
+    }
+
+    /// The first generator of the multiplicative group in the order of
+    /// `element`. Panics if the order does not fit in a `u128`.
+    pub fn primitive_element(&self) -> GenericFiniteFieldElem<T> {
+        let q = field_order(self);
+        self.elements().skip(1)
+            .find(|a| multiplicative_order(self, a) == Some(q - 1))
+            .unwrap()
+    }
+}
+
+impl<T: FiniteField> NewElement<&GenericFiniteFieldElem<T>> for GenericFiniteField<T> {
+    #[inline]
+    fn new(&self, val: &GenericFiniteFieldElem<T>) -> GenericFiniteFieldElem<T> {
+        val.clone()
+    }
+}
+
+impl<T: FiniteField> NewElement<&GenericPoly<T>> for GenericFiniteField<T> {
+    #[inline]
+    fn new(&self, val: &GenericPoly<T>) -> GenericFiniteFieldElem<T> {
+        self.from_poly(val.clone())
+    }
+}
+
+impl<T: FiniteField> NewElement<GenericPoly<T>> for GenericFiniteField<T> {
+    #[inline]
+    fn new(&self, val: GenericPoly<T>) -> GenericFiniteFieldElem<T> {
+        self.from_poly(val)
+    }
+}
+
+impl<S, T, const CAP: usize> NewElement<[S; CAP]> for GenericFiniteField<T>
+where
+    T: FiniteField + NewElement<S>,
+{
+    #[inline]
+    fn new(&self, coeffs: [S; CAP]) -> GenericFiniteFieldElem<T> {
+        self.from_poly(self.poly_ring().new(coeffs))
+    }
+}
+
+impl<S, T> NewElement<Vec<S>> for GenericFiniteField<T>
+where
+    T: FiniteField + Ring<Element=S>
+{
+    #[inline]
+    fn new(&self, coeffs: Vec<S>) -> GenericFiniteFieldElem<T> {
+        self.from_quotient(self.ctx.quotient.new(coeffs))
+    }
+}
+
+impl<T: FiniteField> fmt::Display for GenericFiniteField<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Finite field of order {}^{} with modulus {}",
+            self.characteristic(),
+            self.degree(),
+            self.modulus()
+        )
+    }
+}
+
+impl<T: FiniteField + PartialEq> Eq for GenericFiniteField<T> {}
+
+impl<T: FiniteField + PartialEq> PartialEq for GenericFiniteField<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericFiniteField<T>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) || self.ctx.quotient == rhs.ctx.quotient
+    }
+}
+
+impl<T: FiniteField + Hash> Hash for GenericFiniteField<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ctx.quotient.hash(state);
+    }
+}
+
+impl<T: FiniteField> Parent for GenericFiniteField<T> {
+    type Element = GenericFiniteFieldElem<T>;
+}
+
+impl<T: FiniteField> Identity<Additive> for GenericFiniteField<T> {
+    #[inline]
+    fn identity(&self) -> GenericFiniteFieldElem<T> {
+        self.from_quotient(self.ctx.quotient.zero())
+    }
+}
+
+impl<T: FiniteField> Divisible<Additive> for GenericFiniteField<T> {}
+
+impl<T: FiniteField> Associative<Additive> for GenericFiniteField<T> {}
+
+impl<T: FiniteField> Commutative<Additive> for GenericFiniteField<T> {}
+
+impl<T: FiniteField> Identity<Multiplicative> for GenericFiniteField<T> {
+    #[inline]
+    fn identity(&self) -> GenericFiniteFieldElem<T> {
+        self.from_quotient(self.ctx.quotient.one())
+    }
+}
+
+impl<T: FiniteField> Divisible<Multiplicative> for GenericFiniteField<T> {}
+
+impl<T: FiniteField> Associative<Multiplicative> for GenericFiniteField<T> {}
+
+impl<T: FiniteField> Commutative<Multiplicative> for GenericFiniteField<T> {}
+
+impl<T: FiniteField> Distributive for GenericFiniteField<T> {}
+
+impl<T: FiniteField> InvertibleRing for GenericFiniteField<T> {
+    #[inline]
+    fn inverse(&self, a: &GenericFiniteFieldElem<T>) -> Option<GenericFiniteFieldElem<T>> {
+        a.inv()
+    }
+}
+
+impl<T: FiniteField> ExactDivisionRing for GenericFiniteField<T> {
+    #[inline]
+    fn divexact(
+        &self,
+        a: &GenericFiniteFieldElem<T>,
+        b: &GenericFiniteFieldElem<T>
+    ) -> Option<GenericFiniteFieldElem<T>> {
+        Some(a * &b.inv()?)
+    }
+}
+
+impl<T: FiniteField> DivisionRing for GenericFiniteField<T> {}
+
+impl<T: FiniteField> DetRing for GenericFiniteField<T> {
+    #[inline]
+    fn det(&self, a: &[GenericFiniteFieldElem<T>], n: usize) -> GenericFiniteFieldElem<T> {
+        det_gaussian(self, a, n)
+    }
+
+    #[inline]
+    fn charpoly(&self, a: &[GenericFiniteFieldElem<T>], n: usize) -> Vec<GenericFiniteFieldElem<T>> {
+        charpoly_hessenberg(self, a, n)
+    }
+
+    #[inline]
+    fn is_integral_domain(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn domain_divexact(
+        &self,
+        a: &GenericFiniteFieldElem<T>,
+        b: &GenericFiniteFieldElem<T>
+    ) -> Option<GenericFiniteFieldElem<T>> {
+        self.divexact(a, b)
+    }
+}
+
+impl<T: FiniteField> FiniteField for GenericFiniteField<T> {
+    #[inline]
+    fn characteristic(&self) -> u64 {
+        self.base_ring().characteristic()
+    }
+
+    #[inline]
+    fn degree(&self) -> u32 {
+        self.base_ring().degree() * self.relative_degree() as u32
+    }
+
+    /// The element whose coefficients are the base field elements indexed by
+    /// the base `q` digits of `i`, least significant first, where `q` is the
+    /// order of the base field.
+    fn element(&self, mut i: u128) -> GenericFiniteFieldElem<T> {
+        let base = self.base_ring();
+        let q = field_order(base);
+        let mut coeffs = Vec::with_capacity(self.relative_degree());
+        for _ in 0..self.relative_degree() {
+            coeffs.push(base.element(i % q));
+            i /= q;
+        }
+        self.new(coeffs)
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// GenericFiniteFieldElem<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericFiniteFieldElem<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericFiniteFieldCtx<T>>,
+    pub(crate) val: GenericPolyQuotient<T>,
+}
+
+impl<T: FiniteField> fmt::Display for GenericFiniteFieldElem<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
+
+impl<T: FiniteField> Eq for GenericFiniteFieldElem<T> {}
+
+impl<T: FiniteField> PartialEq for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericFiniteFieldElem<T>) -> bool {
+        self.val == rhs.val
+    }
+}
+
+impl<T: FiniteField + Hash> Hash for GenericFiniteFieldElem<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.val.hash(state);
+    }
+}
+
+impl<T: FiniteField> Element for GenericFiniteFieldElem<T> {
+    type Parent = GenericFiniteField<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericFiniteField<T> {
+        GenericFiniteField {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: FiniteField> Operation<Additive> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: FiniteField> IsIdentity<Additive> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.val.poly.deg().is_none()
+    }
+}
+
+impl<T: FiniteField> TwoSidedInverse<Additive> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: FiniteField> Operation<Multiplicative> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: FiniteField> IsIdentity<Multiplicative> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        IsIdentity::<Multiplicative>::is_identity(&self.val)
+    }
+}
+
+/// Panics on zero.
+impl<T: FiniteField> TwoSidedInverse<Multiplicative> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inv().expect("Division by zero.")
+    }
+}
+
+impl<T: FiniteField> GenericFiniteFieldElem<T> {
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.quotient.base_ring()
+    }
+
+    /// The representative of degree less than that of the modulus.
+    #[inline]
+    pub fn lift(&self) -> &GenericPoly<T> {
+        self.val.lift()
+    }
+
+    /// The multiplicative inverse, or `None` for zero.
+    #[inline]
+    pub fn inv(&self) -> Option<Self> {
+        Some(self.parent().from_quotient(self.val.inv()?))
+    }
+
+    /// Raise to the power `e` by repeated squaring.
+    #[inline]
+    pub fn pow_ui(&self, e: u64) -> Self {
+        self.parent().from_quotient(self.val.pow_ui(e))
+    }
+
+    /// The image under the Frobenius automorphism `a -> a^p`.
+    #[inline]
+    pub fn frobenius(&self) -> Self {
+        self.parent().frobenius(self)
+    }
+
+    /// The image under the `k`-th power of the Frobenius automorphism.
+    pub fn frobenius_pow(&self, k: u32) -> Self {
+        let field = self.parent();
+        (0..k % field.degree()).fold(self.clone(), |a, _| field.frobenius(&a))
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.parent().is_square(self)
+    }
+
+    /// A square root, or `None` if `self` is not a square.
+    #[inline]
+    pub fn sqrt(&self) -> Option<Self> {
+        self.parent().sqrt(self)
+    }
+
+    /// The multiplicative order, or `None` for zero.
+    #[inline]
+    pub fn multiplicative_order(&self) -> Option<u128> {
+        self.parent().multiplicative_order(self)
+    }
+
+    /// The norm to the base field.
+    #[inline]
+    pub fn norm(&self) -> Elem<T> {
+        self.val.norm()
+    }
+
+    /// The trace to the base field.
+    #[inline]
+    pub fn trace(&self) -> Elem<T> {
+        self.val.trace()
+    }
+
+    /// The minimal polynomial over the base field.
+    #[inline]
+    pub fn minpoly(&self) -> GenericPoly<T> {
+        self.val.minpoly()
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::finite_field::GenericFiniteFieldElem;
+use crate::traits::FiniteField;
+
+// Neg
+impl<T: FiniteField> NegAssign for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.val.neg_assign();
+    }
+}
+
+impl<T: FiniteField> Neg for GenericFiniteFieldElem<T> {
+    type Output = GenericFiniteFieldElem<T>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: FiniteField> Neg for &GenericFiniteFieldElem<T> {
+    type Output = GenericFiniteFieldElem<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl<T: FiniteField> AddAssign<&Self> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.val.add_assign(&rhs.val);
+    }
+}
+
+impl<T: FiniteField> AddFrom<&Self> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn add_from(&mut self, lhs: &Self) {
+        self.val.add_from(&lhs.val);
+    }
+}
+
+forward_binop! {
+    GenericFiniteFieldElem<T: FiniteField>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+impl<T: FiniteField> SubAssign<&Self> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.val.sub_assign(&rhs.val);
+    }
+}
+
+impl<T: FiniteField> SubFrom<&Self> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        self.val.sub_from(&lhs.val);
+    }
+}
+
+forward_binop! {
+    GenericFiniteFieldElem<T: FiniteField>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+impl<T: FiniteField> MulAssign<&Self> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.val.mul_assign(&rhs.val);
+    }
+}
+
+impl<T: FiniteField> MulFrom<&Self> for GenericFiniteFieldElem<T> {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        self.val.mul_from(&lhs.val);
+    }
+}
+
+forward_binop! {
+    GenericFiniteFieldElem<T: FiniteField>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
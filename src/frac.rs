@@ -0,0 +1,445 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::mat::{charpoly_hessenberg, det_gaussian};
+use crate::traits::{DetRing, DivisionRing, ExactDivisionRing, GcdRing, InvertibleRing};
+use crate::util::check_unital;
+
+mod ops;
+mod rational;
+
+pub use rational::{
+    partial_fractions,
+    GenericRationalFunction,
+    GenericRationalFunctionField,
+    PartialFractions
+};
+
+/// Errors raised by operations on fractions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FracError {
+    /// The denominator vanishes at the evaluation point.
+    Pole,
+    /// The factors are not pairwise coprime or do not multiply to the
+    /// denominator up to a unit.
+    InvalidFactorization,
+}
+
+impl fmt::Display for FracError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FracError::Pole =>
+                write!(f, "denominator vanishes at the evaluation point"),
+            FracError::InvalidFactorization =>
+                write!(f, "invalid factorization of the denominator"),
+        }
+    }
+}
+
+impl Error for FracError {}
+
+///////////////////////////////////////////////////////////////////
+// GenericFracField<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct GenericFracCtx<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) base_ring: T,
+}
+
+impl<T> GenericFracCtx<T> {
+    pub fn new(base_ring: T) -> Self {
+        GenericFracCtx { base_ring }
+    }
+}
+
+/// The field of fractions of a GCD domain. Elements are kept reduced, with
+/// the denominator in canonical form (e.g. positive, or monic).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericFracField<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericFracCtx<T>>,
+}
+
+impl<T: GcdRing> GenericFracField<T> {
+    /// Panics if the base ring has no identity.
+    #[inline]
+    pub fn init(base_ring: &T) -> Self {
+        check_unital(base_ring);
+        GenericFracField {
+            ctx: Rc::new(GenericFracCtx::new(base_ring.clone()))
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        &self.ctx.base_ring
+    }
+
+    /// The fraction `num/den`, or `None` if `den` is zero.
+    pub fn from_parts(&self, num: Elem<T>, den: Elem<T>) -> Option<GenericFrac<T>> {
+        if den.is_zero() {
+            return None;
+        }
+        let mut res = GenericFrac {
+            ctx: Rc::clone(&self.ctx),
+            num,
+            den,
+        };
+        res.normalize();
+        Some(res)
+    }
+
+    /// The fraction `num/1`.
+    #[inline]
+    pub fn from_base(&self, num: Elem<T>) -> GenericFrac<T> {
+        GenericFrac {
+            ctx: Rc::clone(&self.ctx),
+            num,
+            den: self.base_ring().one(),
+        }
+    }
+}
+
+impl<T: GcdRing> NewElement<&GenericFrac<T>> for GenericFracField<T> {
+    #[inline]
+    fn new(&self, val: &GenericFrac<T>) -> GenericFrac<T> {
+        val.clone()
+    }
+}
+
+/// Panics if the denominator is zero.
+impl<S, T> NewElement<(S, S)> for GenericFracField<T>
+where
+    T: GcdRing + NewElement<S>,
+{
+    #[inline]
+    fn new(&self, val: (S, S)) -> GenericFrac<T> {
+        let num = self.base_ring().new(val.0);
+        let den = self.base_ring().new(val.1);
+        self.from_parts(num, den).expect("Denominator is zero.")
+    }
+}
+
+impl<T: GcdRing + fmt::Display> fmt::Display for GenericFracField<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Fraction field of {}", self.base_ring())
+    }
+}
+
+impl<T: GcdRing + PartialEq> Eq for GenericFracField<T> {}
+
+impl<T: GcdRing + PartialEq> PartialEq for GenericFracField<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericFracField<T>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) ||
+            self.base_ring() == rhs.base_ring()
+    }
+}
+
+impl<T: GcdRing + Hash> Hash for GenericFracField<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base_ring().hash(state);
+    }
+}
+
+impl<T: GcdRing> Parent for GenericFracField<T> {
+    type Element = GenericFrac<T>;
+}
+
+impl<T: GcdRing> Identity<Additive> for GenericFracField<T> {
+    #[inline]
+    fn identity(&self) -> GenericFrac<T> {
+        self.from_base(self.base_ring().zero())
+    }
+}
+
+impl<T: GcdRing> Divisible<Additive> for GenericFracField<T> {}
+
+impl<T: GcdRing> Associative<Additive> for GenericFracField<T> {}
+
+impl<T: GcdRing> Commutative<Additive> for GenericFracField<T> {}
+
+impl<T: GcdRing> Identity<Multiplicative> for GenericFracField<T> {
+    #[inline]
+    fn identity(&self) -> GenericFrac<T> {
+        self.from_base(self.base_ring().one())
+    }
+}
+
+impl<T: GcdRing> Divisible<Multiplicative> for GenericFracField<T> {}
+
+impl<T: GcdRing> Associative<Multiplicative> for GenericFracField<T> {}
+
+impl<T: GcdRing> Commutative<Multiplicative> for GenericFracField<T> {}
+
+impl<T: GcdRing> Distributive for GenericFracField<T> {}
+
+impl<T: GcdRing> InvertibleRing for GenericFracField<T> {
+    #[inline]
+    fn inverse(&self, a: &GenericFrac<T>) -> Option<GenericFrac<T>> {
+        a.inv()
+    }
+}
+
+impl<T: GcdRing> ExactDivisionRing for GenericFracField<T> {
+    #[inline]
+    fn divexact(&self, a: &GenericFrac<T>, b: &GenericFrac<T>) -> Option<GenericFrac<T>> {
+        Some(a * &b.inv()?)
+    }
+}
+
+impl<T: GcdRing> DivisionRing for GenericFracField<T> {}
+
+impl<T: GcdRing> DetRing for GenericFracField<T> {
+    #[inline]
+    fn det(&self, a: &[GenericFrac<T>], n: usize) -> GenericFrac<T> {
+        det_gaussian(self, a, n)
+    }
+
+    #[inline]
+    fn charpoly(&self, a: &[GenericFrac<T>], n: usize) -> Vec<GenericFrac<T>> {
+        charpoly_hessenberg(self, a, n)
+    }
+
+    #[inline]
+    fn is_integral_domain(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn domain_divexact(&self, a: &GenericFrac<T>, b: &GenericFrac<T>) -> Option<GenericFrac<T>> {
+        self.divexact(a, b)
+    }
+}
+
+impl<T: GcdRing> GcdRing for GenericFracField<T> {
+    #[inline]
+    fn gcd(&self, a: &GenericFrac<T>, b: &GenericFrac<T>) -> GenericFrac<T> {
+        if a.num.is_zero() && b.num.is_zero() {
+            self.from_base(self.base_ring().zero())
+        } else {
+            self.from_base(self.base_ring().one())
+        }
+    }
+
+    #[inline]
+    fn canonical_unit(&self, a: &GenericFrac<T>) -> GenericFrac<T> {
+        if a.num.is_zero() {
+            self.from_base(self.base_ring().one())
+        } else {
+            a.clone()
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// GenericFrac<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericFrac<T: GcdRing> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericFracCtx<T>>,
+    pub(crate) num: Elem<T>,
+    pub(crate) den: Elem<T>,
+}
+
+impl<T: GcdRing> fmt::Display for GenericFrac<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den.is_one() {
+            return write!(f, "{}", self.num);
+        }
+        let num = format!("{}", self.num);
+        let den = format!("{}", self.den);
+        let wrap = |s: String| if s.contains(' ') { format!("({})", s) } else { s };
+        write!(f, "{}/{}", wrap(num), wrap(den))
+    }
+}
+
+impl<T: GcdRing> Eq for GenericFrac<T> {}
+
+impl<T: GcdRing> PartialEq for GenericFrac<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericFrac<T>) -> bool {
+        self.num == rhs.num && self.den == rhs.den
+    }
+}
+
+impl<T: GcdRing + Hash> Hash for GenericFrac<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent().hash(state);
+        self.num.hash(state);
+        self.den.hash(state);
+    }
+}
+
+impl<T: GcdRing> Element for GenericFrac<T> {
+    type Parent = GenericFracField<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericFracField<T> {
+        GenericFracField {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: GcdRing> Operation<Additive> for GenericFrac<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: GcdRing> IsIdentity<Additive> for GenericFrac<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.num.is_zero()
+    }
+}
+
+impl<T: GcdRing> TwoSidedInverse<Additive> for GenericFrac<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: GcdRing> Operation<Multiplicative> for GenericFrac<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: GcdRing> IsIdentity<Multiplicative> for GenericFrac<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.num.is_one() && self.den.is_one()
+    }
+}
+
+/// Panics on zero.
+impl<T: GcdRing> TwoSidedInverse<Multiplicative> for GenericFrac<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inv().expect("Division by zero.")
+    }
+}
+
+impl<T: GcdRing> GenericFrac<T> {
+    // cancel common factors and put the denominator in canonical form
+    pub(crate) fn normalize(&mut self) {
+        let ring = &self.ctx.base_ring;
+        if !self.num.is_zero() {
+            let g = ring.gcd(&self.num, &self.den);
+            if !g.is_one() {
+                self.num = ring.divexact(&self.num, &g).unwrap();
+                self.den = ring.divexact(&self.den, &g).unwrap();
+            }
+        }
+        self.canonicalize();
+    }
+
+    // put the denominator of a reduced fraction in canonical form
+    pub(crate) fn canonicalize(&mut self) {
+        let ring = &self.ctx.base_ring;
+        if self.num.is_zero() {
+            self.den = ring.one();
+            return;
+        }
+        let u = ring.canonical_unit(&self.den);
+        if !u.is_one() {
+            self.num = ring.divexact(&self.num, &u).unwrap();
+            self.den = ring.divexact(&self.den, &u).unwrap();
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        &self.ctx.base_ring
+    }
+
+    #[inline]
+    pub fn numerator(&self) -> &Elem<T> {
+        &self.num
+    }
+
+    #[inline]
+    pub fn denominator(&self) -> &Elem<T> {
+        &self.den
+    }
+
+    #[inline]
+    pub fn into_parts(self) -> (Elem<T>, Elem<T>) {
+        (self.num, self.den)
+    }
+
+    /// The multiplicative inverse, or `None` for zero.
+    pub fn inv(&self) -> Option<Self> {
+        if self.num.is_zero() {
+            return None;
+        }
+        let mut res = GenericFrac {
+            ctx: Rc::clone(&self.ctx),
+            num: self.den.clone(),
+            den: self.num.clone(),
+        };
+        res.canonicalize();
+        Some(res)
+    }
+}
@@ -0,0 +1,236 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+use std::ops::{Div, DivAssign};
+
+use crate::frac::GenericFrac;
+use crate::traits::{ExactDivisionRing, GcdRing};
+
+// Neg
+impl<T: GcdRing> NegAssign for GenericFrac<T> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.num.neg_assign();
+    }
+}
+
+impl<T: GcdRing> Neg for GenericFrac<T> {
+    type Output = GenericFrac<T>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: GcdRing> Neg for &GenericFrac<T> {
+    type Output = GenericFrac<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+impl<T: GcdRing> GenericFrac<T> {
+    // a/b + c/d, or a/b - c/d if sub is true. Using g = gcd(b, d), this is
+    // (a*(d/g) + c*(b/g))/(b*(d/g)), and only g needs to be cancelled again
+    fn add_sub(&mut self, rhs: &GenericFrac<T>, sub: bool) {
+        let ring = &self.ctx.base_ring;
+        let g = ring.gcd(&self.den, &rhs.den);
+        let (b, d) = if g.is_one() {
+            (self.den.clone(), rhs.den.clone())
+        } else {
+            (
+                ring.divexact(&self.den, &g).unwrap(),
+                ring.divexact(&rhs.den, &g).unwrap()
+            )
+        };
+
+        let mut t = rhs.num.clone();
+        t.mul_assign(&b);
+        self.num.mul_assign(&d);
+        if sub {
+            self.num.sub_assign(&t);
+        } else {
+            self.num.add_assign(&t);
+        }
+        self.den.mul_assign(&d);
+
+        if !g.is_one() && !self.num.is_zero() {
+            let h = ring.gcd(&self.num, &g);
+            if !h.is_one() {
+                self.num = ring.divexact(&self.num, &h).unwrap();
+                self.den = ring.divexact(&self.den, &h).unwrap();
+            }
+        }
+        self.canonicalize();
+    }
+
+    // a/b * c/d, cancelling gcd(a, d) and gcd(c, b) first
+    fn mul_reduced(&mut self, rhs: &GenericFrac<T>) {
+        let ring = &self.ctx.base_ring;
+        if self.num.is_zero() || rhs.num.is_zero() {
+            self.num = ring.zero();
+            self.canonicalize();
+            return;
+        }
+
+        let g1 = ring.gcd(&self.num, &rhs.den);
+        let g2 = ring.gcd(&rhs.num, &self.den);
+        let mut c = rhs.num.clone();
+        let mut d = rhs.den.clone();
+        if !g1.is_one() {
+            self.num = ring.divexact(&self.num, &g1).unwrap();
+            d = ring.divexact(&d, &g1).unwrap();
+        }
+        if !g2.is_one() {
+            c = ring.divexact(&c, &g2).unwrap();
+            self.den = ring.divexact(&self.den, &g2).unwrap();
+        }
+        self.num.mul_assign(&c);
+        self.den.mul_assign(&d);
+        self.canonicalize();
+    }
+}
+
+// Add
+impl<T: GcdRing> AddAssign<&GenericFrac<T>> for GenericFrac<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &GenericFrac<T>) {
+        self.add_sub(rhs, false);
+    }
+}
+
+impl<T: GcdRing> AddFrom<&GenericFrac<T>> for GenericFrac<T> {
+    #[inline]
+    fn add_from(&mut self, lhs: &GenericFrac<T>) {
+        self.add_sub(lhs, false);
+    }
+}
+
+forward_binop! {
+    GenericFrac<T: GcdRing>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+
+impl<T: GcdRing> SubAssign<&Self> for GenericFrac<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.add_sub(rhs, true);
+    }
+}
+
+impl<T: GcdRing> SubFrom<&Self> for GenericFrac<T> {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        self.add_sub(lhs, true);
+        self.neg_assign();
+    }
+}
+
+forward_binop! {
+    GenericFrac<T: GcdRing>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+
+impl<T: GcdRing> MulAssign<&Self> for GenericFrac<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_reduced(rhs);
+    }
+}
+
+impl<T: GcdRing> MulFrom<&Self> for GenericFrac<T> {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        self.mul_reduced(lhs);
+    }
+}
+
+forward_binop! {
+    GenericFrac<T: GcdRing>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
+
+// Div
+
+impl<T: GcdRing> DivAssign<&GenericFrac<T>> for GenericFrac<T> {
+    /// Panics on division by zero.
+    fn div_assign(&mut self, rhs: &GenericFrac<T>) {
+        let inv = rhs.inv().expect("Division by zero.");
+        self.mul_assign(&inv);
+    }
+}
+
+impl<T: GcdRing> DivAssign<GenericFrac<T>> for GenericFrac<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: GenericFrac<T>) {
+        self.div_assign(&rhs);
+    }
+}
+
+impl<T: GcdRing> Div for GenericFrac<T> {
+    type Output = GenericFrac<T>;
+    #[inline]
+    fn div(mut self, rhs: Self) -> Self::Output {
+        self.div_assign(&rhs);
+        self
+    }
+}
+
+impl<T: GcdRing> Div<&GenericFrac<T>> for GenericFrac<T> {
+    type Output = GenericFrac<T>;
+    #[inline]
+    fn div(mut self, rhs: &Self) -> Self::Output {
+        self.div_assign(rhs);
+        self
+    }
+}
+
+impl<T: GcdRing> Div<GenericFrac<T>> for &GenericFrac<T> {
+    type Output = GenericFrac<T>;
+    #[inline]
+    fn div(self, rhs: GenericFrac<T>) -> Self::Output {
+        let mut res = self.clone();
+        res.div_assign(&rhs);
+        res
+    }
+}
+
+impl<T: GcdRing> Div<&GenericFrac<T>> for &GenericFrac<T> {
+    type Output = GenericFrac<T>;
+    #[inline]
+    fn div(self, rhs: &GenericFrac<T>) -> Self::Output {
+        let mut res = self.clone();
+        res.div_assign(rhs);
+        res
+    }
+}
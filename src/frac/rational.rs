@@ -0,0 +1,154 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use crate::frac::{FracError, GenericFrac, GenericFracField};
+use crate::poly::{GenericPoly, GenericPolyRing};
+use crate::traits::{DivisionRing, InvertibleRing};
+
+/// The field of rational functions `K(x)` over a field `K`.
+pub type GenericRationalFunctionField<T> = GenericFracField<GenericPolyRing<T>>;
+
+/// A rational function, stored as a reduced fraction with monic denominator.
+pub type GenericRationalFunction<T> = GenericFrac<GenericPolyRing<T>>;
+
+/// A partial fraction decomposition `poly + sum num/factor^power`.
+#[derive(Clone)]
+pub struct PartialFractions<T: Ring> {
+    /// The polynomial part.
+    pub poly: GenericPoly<T>,
+    /// The terms `(num, factor, power)` with `deg(num) < deg(factor)`.
+    pub terms: Vec<(GenericPoly<T>, GenericPoly<T>, usize)>,
+}
+
+impl<T: DivisionRing> PartialFractions<T> {
+    /// Recombine the decomposition into a single rational function.
+    pub fn to_rational_function(&self) -> GenericRationalFunction<T> {
+        let field = GenericFracField::init(&self.poly.parent());
+        let mut res = field.from_base(self.poly.clone());
+        for (num, f, k) in self.terms.iter() {
+            let den = (0..*k).fold(field.base_ring().one(), |acc, _| &acc * f);
+            res.add_assign(&field.from_parts(num.clone(), den).unwrap());
+        }
+        res
+    }
+}
+
+/// The partial fraction decomposition of `num/den` over a field, given `den`
+/// up to a unit as a product of pairwise coprime factors `f^e`, for example
+/// its square-free or complete factorization. The fraction need not be
+/// reduced.
+pub fn partial_fractions<T: DivisionRing>(
+    num: &GenericPoly<T>,
+    den: &GenericPoly<T>,
+    factors: &[(GenericPoly<T>, usize)]
+) -> Result<PartialFractions<T>, FracError> {
+    let parent = den.parent();
+    let powers: Vec<GenericPoly<T>> = factors.iter()
+        .map(|(f, e)| (0..*e).fold(parent.one(), |acc, _| &acc * f))
+        .collect();
+    let prod = powers.iter().fold(parent.one(), |acc, p| &acc * p);
+
+    // r/den = (r/u)/prod for the unit u = den/prod
+    let unit = match den.divexact(&prod) {
+        Some(u) if u.deg() == Some(0) => u,
+        _ => return Err(FracError::InvalidFactorization),
+    };
+    let (q, r) = num.divrem(den).unwrap();
+    let r = r.scalar_mul(&den.base_ring().inverse(&unit.coeffs[0]).unwrap());
+
+    let mut terms = Vec::new();
+    for ((f, e), fe) in factors.iter().zip(powers.iter()) {
+        // the numerator over f^e is r/(prod/f^e) mod f^e
+        let cofactor = prod.divexact(fe).unwrap();
+        let (g, s, _) = cofactor.xgcd(fe);
+        if g.deg() != Some(0) {
+            return Err(FracError::InvalidFactorization);
+        }
+        let (_, mut a) = (&r * &s).divrem(fe).unwrap();
+
+        // expand in powers of f: a/f^e = sum c_j/f^(e-j) with a = sum c_j*f^j
+        for j in 0..*e {
+            let (qa, c) = a.divrem(f).unwrap();
+            if c.deg().is_some() {
+                terms.push((c, f.clone(), e - j));
+            }
+            a = qa;
+        }
+    }
+    Ok(PartialFractions { poly: q, terms })
+}
+
+impl<T: DivisionRing> GenericFracField<GenericPolyRing<T>> {
+    /// The field of rational functions in `var` over `base_ring`.
+    #[inline]
+    pub fn rational_functions<S: Into<String>>(base_ring: &T, var: S) -> Self {
+        Self::init(&GenericPolyRing::init(base_ring, var))
+    }
+
+    /// The generator `x`.
+    pub fn gen(&self) -> GenericRationalFunction<T> {
+        let mut x = self.base_ring().zero();
+        x.set_coefficient(1, self.base_ring().base_ring().one());
+        self.from_base(x)
+    }
+}
+
+impl<T: DivisionRing> GenericFrac<GenericPolyRing<T>> {
+    /// Whether `a` is a pole, i.e. a root of the denominator.
+    #[inline]
+    pub fn is_pole(&self, a: &Elem<T>) -> bool {
+        self.den.evaluate(a).is_zero()
+    }
+
+    /// Evaluate at `a`, failing if `a` is a pole.
+    pub fn evaluate(&self, a: &Elem<T>) -> Result<Elem<T>, FracError> {
+        let d = self.den.evaluate(a);
+        let inv = self.base_ring().base_ring().inverse(&d).ok_or(FracError::Pole)?;
+        let mut res = self.num.evaluate(a);
+        res.mul_assign(&inv);
+        Ok(res)
+    }
+
+    /// The derivative `(n'd - nd')/d^2`.
+    pub fn derivative(&self) -> Self {
+        let num = &self.num.derivative() * &self.den - &self.num * &self.den.derivative();
+        let den = &self.den * &self.den;
+        self.parent().from_parts(num, den).unwrap()
+    }
+
+    /// The partial fraction decomposition with respect to the square-free
+    /// factorization of the denominator. Only valid in characteristic zero
+    /// or characteristic greater than the degree of the denominator.
+    pub fn partial_fractions(&self) -> Result<PartialFractions<T>, FracError> {
+        let factors = self.den.squarefree_factorization();
+        partial_fractions(&self.num, &self.den, &factors)
+    }
+
+    /// The partial fraction decomposition with respect to a factorization of
+    /// the denominator into pairwise coprime factors `f^e`.
+    #[inline]
+    pub fn partial_fractions_with(
+        &self,
+        factors: &[(GenericPoly<T>, usize)]
+    ) -> Result<PartialFractions<T>, FracError> {
+        partial_fractions(&self.num, &self.den, factors)
+    }
+}
@@ -0,0 +1,303 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::mat::det_bareiss;
+use crate::traits::{DetRing, EuclideanRing, ExactDivisionRing, GcdRing, InvertibleRing};
+
+mod ops;
+
+///////////////////////////////////////////////////////////////////
+// IntegerRing
+///////////////////////////////////////////////////////////////////
+
+/// The ring of integers, with elements stored as `i128`. Arithmetic panics
+/// on overflow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntegerRing;
+
+impl IntegerRing {
+    #[inline]
+    pub fn init() -> Self {
+        IntegerRing
+    }
+
+    #[inline]
+    pub fn from_i128(&self, val: i128) -> Integer {
+        Integer { val }
+    }
+}
+
+impl NewElement<&Integer> for IntegerRing {
+    #[inline]
+    fn new(&self, val: &Integer) -> Integer {
+        val.clone()
+    }
+}
+
+macro_rules! impl_new_element {
+    ($($t:ident)*) => ($(
+        impl NewElement<$t> for IntegerRing {
+            #[inline]
+            fn new(&self, val: $t) -> Integer {
+                self.from_i128(val as i128)
+            }
+        }
+
+        impl NewElement<&$t> for IntegerRing {
+            #[inline]
+            fn new(&self, val: &$t) -> Integer {
+                self.from_i128(*val as i128)
+            }
+        }
+    )*)
+}
+
+impl_new_element! { usize u8 u16 u32 u64 isize i8 i16 i32 i64 }
+
+impl NewElement<i128> for IntegerRing {
+    #[inline]
+    fn new(&self, val: i128) -> Integer {
+        self.from_i128(val)
+    }
+}
+
+impl fmt::Display for IntegerRing {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Integer ring")
+    }
+}
+
+impl Parent for IntegerRing {
+    type Element = Integer;
+}
+
+impl Identity<Additive> for IntegerRing {
+    #[inline]
+    fn identity(&self) -> Integer {
+        Integer { val: 0 }
+    }
+}
+
+impl Divisible<Additive> for IntegerRing {}
+
+impl Associative<Additive> for IntegerRing {}
+
+impl Commutative<Additive> for IntegerRing {}
+
+impl Identity<Multiplicative> for IntegerRing {
+    #[inline]
+    fn identity(&self) -> Integer {
+        Integer { val: 1 }
+    }
+}
+
+impl Associative<Multiplicative> for IntegerRing {}
+
+impl Commutative<Multiplicative> for IntegerRing {}
+
+impl Distributive for IntegerRing {}
+
+impl InvertibleRing for IntegerRing {
+    #[inline]
+    fn inverse(&self, a: &Integer) -> Option<Integer> {
+        if a.is_unit() {
+            Some(a.clone())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn is_unit(&self, a: &Integer) -> bool {
+        a.is_unit()
+    }
+}
+
+impl ExactDivisionRing for IntegerRing {
+    #[inline]
+    fn divexact(&self, a: &Integer, b: &Integer) -> Option<Integer> {
+        if b.val == 0 || a.val % b.val != 0 {
+            None
+        } else {
+            Some(Integer { val: a.val / b.val })
+        }
+    }
+}
+
+impl DetRing for IntegerRing {
+    #[inline]
+    fn det(&self, a: &[Integer], n: usize) -> Integer {
+        det_bareiss(self, a, n)
+    }
+
+    #[inline]
+    fn is_integral_domain(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn domain_divexact(&self, a: &Integer, b: &Integer) -> Option<Integer> {
+        self.divexact(a, b)
+    }
+}
+
+impl EuclideanRing for IntegerRing {
+    #[inline]
+    fn divrem(&self, a: &Integer, b: &Integer) -> Option<(Integer, Integer)> {
+        a.divrem(b)
+    }
+}
+
+impl GcdRing for IntegerRing {
+    #[inline]
+    fn gcd(&self, a: &Integer, b: &Integer) -> Integer {
+        a.gcd(b)
+    }
+
+    /// The sign, or one for zero.
+    #[inline]
+    fn canonical_unit(&self, a: &Integer) -> Integer {
+        Integer { val: if a.val < 0 { -1 } else { 1 } }
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// Integer
+///////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Integer {
+    pub(crate) val: i128,
+}
+
+impl fmt::Display for Integer {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
+
+impl Element for Integer {
+    type Parent = IntegerRing;
+
+    #[inline]
+    fn parent(&self) -> IntegerRing {
+        IntegerRing
+    }
+}
+
+impl Operation<Additive> for Integer {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl IsIdentity<Additive> for Integer {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.val == 0
+    }
+}
+
+impl TwoSidedInverse<Additive> for Integer {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl Operation<Multiplicative> for Integer {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl IsIdentity<Multiplicative> for Integer {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.val == 1
+    }
+}
+
+impl Integer {
+    #[inline]
+    pub fn value(&self) -> i128 {
+        self.val
+    }
+
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Integer { val: self.val.checked_abs().expect("Integer overflow.") }
+    }
+
+    /// Whether `self` is one or minus one.
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        self.val == 1 || self.val == -1
+    }
+
+    /// The nonnegative greatest common divisor.
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (self.val.unsigned_abs(), rhs.val.unsigned_abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        Integer { val: i128::try_from(a).expect("Integer overflow.") }
+    }
+
+    /// Division with remainder `self = q*rhs + r` with `q` nearest to
+    /// `self/rhs`, so that `|r| <= |rhs|/2`, or `None` if `rhs` is zero.
+    pub fn divrem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.val == 0 {
+            return None;
+        }
+        let mut q = self.val.checked_div_euclid(rhs.val).expect("Integer overflow.");
+        let mut r = self.val.rem_euclid(rhs.val);
+        // 0 <= r < |rhs|, so round up if r is closer to |rhs|
+        let b = rhs.val.unsigned_abs();
+        if r.unsigned_abs() > b - r.unsigned_abs() {
+            r = -((b - r.unsigned_abs()) as i128);
+            q = q.checked_add(rhs.val.signum()).expect("Integer overflow.");
+        }
+        Some((Integer { val: q }, Integer { val: r }))
+    }
+
+    /// Raise to the power `e`.
+    #[inline]
+    pub fn pow(&self, e: u32) -> Self {
+        Integer { val: self.val.checked_pow(e).expect("Integer overflow.") }
+    }
+}
+
+impl From<i128> for Integer {
+    #[inline]
+    fn from(val: i128) -> Self {
+        Integer { val }
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::integer::Integer;
+
+// Neg
+impl NegAssign for Integer {
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.val = self.val.checked_neg().expect("Integer overflow.");
+    }
+}
+
+impl Neg for Integer {
+    type Output = Integer;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl Neg for &Integer {
+    type Output = Integer;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl AddAssign<&Self> for Integer {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.val = self.val.checked_add(rhs.val).expect("Integer overflow.");
+    }
+}
+
+impl AddFrom<&Self> for Integer {
+    #[inline]
+    fn add_from(&mut self, lhs: &Self) {
+        self.val = lhs.val.checked_add(self.val).expect("Integer overflow.");
+    }
+}
+
+forward_binop! {
+    Integer
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+impl SubAssign<&Self> for Integer {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.val = self.val.checked_sub(rhs.val).expect("Integer overflow.");
+    }
+}
+
+impl SubFrom<&Self> for Integer {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        self.val = lhs.val.checked_sub(self.val).expect("Integer overflow.");
+    }
+}
+
+forward_binop! {
+    Integer
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+impl MulAssign<&Self> for Integer {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.val = self.val.checked_mul(rhs.val).expect("Integer overflow.");
+    }
+}
+
+impl MulFrom<&Self> for Integer {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        self.val = lhs.val.checked_mul(self.val).expect("Integer overflow.");
+    }
+}
+
+forward_binop! {
+    Integer
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
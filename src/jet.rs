@@ -0,0 +1,408 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::poly::GenericPoly;
+use crate::traits::{DetRing, InvertibleRing};
+use crate::util::{check_unital, ring_integer};
+
+mod ops;
+
+///////////////////////////////////////////////////////////////////
+// GenericJetRing<T, N>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct GenericJetCtx<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) base_ring: T,
+}
+
+/// The ring `R[e]/(e^N)` of jets, or truncated Taylor expansions
+/// `a_0 + a_1*e + ... + a_{N-1}*e^(N-1)`, over a commutative ring `R`. With
+/// `N = 2` these are the dual numbers.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericJetRing<T, const N: usize> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericJetCtx<T>>,
+}
+
+impl<T: Ring, const N: usize> GenericJetRing<T, N> {
+    /// Panics if `N` is zero or the base ring has no identity, e.g. a
+    /// non-square matrix space.
+    #[inline]
+    pub fn init(base_ring: &T) -> Self {
+        assert!(N > 0, "Jet order must be positive.");
+        check_unital(base_ring);
+        GenericJetRing {
+            ctx: Rc::new(GenericJetCtx { base_ring: base_ring.clone() })
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        &self.ctx.base_ring
+    }
+
+    /// The jet with the given coefficients, truncated or padded with zeros
+    /// to length `N`.
+    pub fn from_coefficients(&self, mut coeffs: Vec<Elem<T>>) -> GenericJet<T, N> {
+        coeffs.resize(N, self.base_ring().zero());
+        GenericJet {
+            ctx: Rc::clone(&self.ctx),
+            coeffs,
+        }
+    }
+
+    /// The constant jet `a`.
+    #[inline]
+    pub fn from_base(&self, a: Elem<T>) -> GenericJet<T, N> {
+        self.from_coefficients(vec![a])
+    }
+
+    /// The jet `a + e` of the independent variable at `a`, so that
+    /// evaluating a function at it gives the derivatives at `a`.
+    pub fn variable(&self, a: Elem<T>) -> GenericJet<T, N> {
+        let mut coeffs = vec![a];
+        if N > 1 {
+            coeffs.push(self.base_ring().one());
+        }
+        self.from_coefficients(coeffs)
+    }
+
+    /// The nilpotent generator `e`.
+    #[inline]
+    pub fn gen(&self) -> GenericJet<T, N> {
+        self.variable(self.base_ring().zero())
+    }
+}
+
+impl<T: Ring, const N: usize> NewElement<&GenericJet<T, N>> for GenericJetRing<T, N> {
+    #[inline]
+    fn new(&self, val: &GenericJet<T, N>) -> GenericJet<T, N> {
+        val.clone()
+    }
+}
+
+impl<S, T, const N: usize, const CAP: usize> NewElement<[S; CAP]> for GenericJetRing<T, N>
+where
+    T: Ring + NewElement<S>,
+{
+    #[inline]
+    fn new(&self, coeffs: [S; CAP]) -> GenericJet<T, N> {
+        let coeffs = coeffs.into_iter().map(|x| self.base_ring().new(x)).collect();
+        self.from_coefficients(coeffs)
+    }
+}
+
+impl<S, T, const N: usize> NewElement<Vec<S>> for GenericJetRing<T, N>
+where
+    T: Ring<Element=S>
+{
+    #[inline]
+    fn new(&self, coeffs: Vec<S>) -> GenericJet<T, N> {
+        self.from_coefficients(coeffs)
+    }
+}
+
+impl<T: Ring + fmt::Display, const N: usize> fmt::Display for GenericJetRing<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Jets of order {} over {}", N, self.base_ring())
+    }
+}
+
+impl<T: Ring + PartialEq, const N: usize> Eq for GenericJetRing<T, N> {}
+
+impl<T: Ring + PartialEq, const N: usize> PartialEq for GenericJetRing<T, N> {
+    #[inline]
+    fn eq(&self, rhs: &GenericJetRing<T, N>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) || self.base_ring() == rhs.base_ring()
+    }
+}
+
+impl<T: Ring + Hash, const N: usize> Hash for GenericJetRing<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base_ring().hash(state);
+        N.hash(state);
+    }
+}
+
+impl<T: Ring, const N: usize> Parent for GenericJetRing<T, N> {
+    type Element = GenericJet<T, N>;
+}
+
+impl<T: Ring, const N: usize> Identity<Additive> for GenericJetRing<T, N> {
+    #[inline]
+    fn identity(&self) -> GenericJet<T, N> {
+        self.from_coefficients(vec![])
+    }
+}
+
+impl<T: Ring, const N: usize> Divisible<Additive> for GenericJetRing<T, N> {}
+
+impl<T: Ring, const N: usize> Associative<Additive> for GenericJetRing<T, N> {}
+
+impl<T: Ring, const N: usize> Commutative<Additive> for GenericJetRing<T, N> {}
+
+impl<T: Ring, const N: usize> Identity<Multiplicative> for GenericJetRing<T, N> {
+    #[inline]
+    fn identity(&self) -> GenericJet<T, N> {
+        self.from_base(self.base_ring().one())
+    }
+}
+
+impl<T: Ring, const N: usize> Associative<Multiplicative> for GenericJetRing<T, N> {}
+
+impl<T: Ring, const N: usize> Commutative<Multiplicative> for GenericJetRing<T, N> {}
+
+impl<T: Ring, const N: usize> Distributive for GenericJetRing<T, N> {}
+
+impl<T: Ring, const N: usize> DetRing for GenericJetRing<T, N> {}
+
+impl<T: InvertibleRing, const N: usize> InvertibleRing for GenericJetRing<T, N> {
+    #[inline]
+    fn inverse(&self, a: &GenericJet<T, N>) -> Option<GenericJet<T, N>> {
+        a.inv()
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// GenericJet<T, N>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericJet<T: Ring, const N: usize> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericJetCtx<T>>,
+    pub(crate) coeffs: Vec<Elem<T>>,
+}
+
+impl<T: Ring, const N: usize> fmt::Display for GenericJet<T, N>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = Vec::with_capacity(N + 1);
+        for (i, c) in self.coeffs.iter().enumerate() {
+            if c.is_zero() {
+                continue;
+            }
+            match i {
+                0 => out.push(format!("{}", c)),
+                1 if c.is_one() => out.push("e".to_string()),
+                1 => out.push(format!("{}*e", c)),
+                _ if c.is_one() => out.push(format!("e^{}", i)),
+                _ => out.push(format!("{}*e^{}", c, i)),
+            }
+        }
+        if out.is_empty() {
+            out.push(format!("{}", self.coeffs[0]));
+        }
+        out.push(format!("O(e^{})", N));
+        write!(f, "{}", out.join(" + "))
+    }
+}
+
+impl<T: Ring, const N: usize> Eq for GenericJet<T, N> {}
+
+impl<T: Ring, const N: usize> PartialEq for GenericJet<T, N> {
+    #[inline]
+    fn eq(&self, rhs: &GenericJet<T, N>) -> bool {
+        self.coeffs == rhs.coeffs
+    }
+}
+
+impl<T: Ring + Hash, const N: usize> Hash for GenericJet<T, N>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent().hash(state);
+        self.coeffs.hash(state);
+    }
+}
+
+impl<T: Ring, const N: usize> Element for GenericJet<T, N> {
+    type Parent = GenericJetRing<T, N>;
+
+    #[inline]
+    fn parent(&self) -> GenericJetRing<T, N> {
+        GenericJetRing {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> Operation<Additive> for GenericJet<T, N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: Ring, const N: usize> IsIdentity<Additive> for GenericJet<T, N> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<T: Ring, const N: usize> TwoSidedInverse<Additive> for GenericJet<T, N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: Ring, const N: usize> Operation<Multiplicative> for GenericJet<T, N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: Ring, const N: usize> IsIdentity<Multiplicative> for GenericJet<T, N> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs[0].is_one() && self.coeffs[1..].iter().all(|c| c.is_zero())
+    }
+}
+
+impl<T: Ring, const N: usize> GenericJet<T, N> {
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        &self.ctx.base_ring
+    }
+
+    /// The coefficients `a_0, ..., a_{N-1}`.
+    #[inline]
+    pub fn coefficients(&self) -> &Vec<Elem<T>> {
+        &self.coeffs
+    }
+
+    /// The coefficient of `e^i`, which is zero for `i >= N`.
+    pub fn get_coefficient(&self, i: usize) -> Elem<T> {
+        match self.coeffs.get(i) {
+            Some(c) => c.clone(),
+            None => self.base_ring().zero(),
+        }
+    }
+
+    /// The value `a_0`.
+    #[inline]
+    pub fn value(&self) -> &Elem<T> {
+        &self.coeffs[0]
+    }
+
+    /// The `k`-th derivative `k!*a_k` of the underlying function.
+    pub fn derivative(&self, k: usize) -> Elem<T> {
+        let ring = self.base_ring();
+        let mut res = self.get_coefficient(k);
+        for i in 2..=k as u64 {
+            res.mul_assign(&ring_integer(ring, i));
+        }
+        res
+    }
+
+    /// Multiply by the scalar `c`.
+    pub fn scalar_mul(&self, c: &Elem<T>) -> Self {
+        let mut res = self.clone();
+        for x in res.coeffs.iter_mut() {
+            x.mul_assign(c);
+        }
+        res
+    }
+
+    /// Raise to the power `e` by repeated squaring.
+    pub fn pow_ui(&self, e: u64) -> Self {
+        let mut res = self.parent().one();
+        for i in (0..64 - e.leading_zeros()).rev() {
+            res = &res * &res;
+            if (e >> i) & 1 == 1 {
+                res = &res * self;
+            }
+        }
+        res
+    }
+
+    /// Evaluate the polynomial `f` at `self` by Horner's rule. At the jet
+    /// `a + e` this gives the Taylor coefficients of `f` at `a`.
+    pub fn evaluate_poly(&self, f: &GenericPoly<T>) -> Self {
+        let parent = self.parent();
+        let mut res = parent.zero();
+        for c in f.coefficients().iter().rev() {
+            res.mul_assign(self);
+            res.coeffs[0].add_assign(c);
+        }
+        res
+    }
+}
+
+impl<T: InvertibleRing, const N: usize> GenericJet<T, N> {
+    /// The inverse, which exists exactly when `a_0` is a unit.
+    pub fn inv(&self) -> Option<Self> {
+        let ring = self.base_ring();
+        let c = ring.inverse(&self.coeffs[0])?;
+
+        // b_k = -c * sum_{j=1}^{k} a_j*b_{k-j}
+        let mut b = Vec::with_capacity(N);
+        b.push(c.clone());
+        for k in 1..N {
+            let mut s = ring.zero();
+            for j in 1..=k {
+                let mut t = self.coeffs[j].clone();
+                t.mul_assign(&b[k - j]);
+                s.add_assign(&t);
+            }
+            s.mul_assign(&c);
+            s.neg_assign();
+            b.push(s);
+        }
+        Some(self.parent().from_coefficients(b))
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::jet::GenericJet;
+
+impl<T: Ring, const N: usize> GenericJet<T, N> {
+    // the truncated product of the coefficients of lhs and rhs
+    fn mul_truncated(lhs: &[Elem<T>], rhs: &[Elem<T>], ring: &T) -> Vec<Elem<T>> {
+        (0..N).map(|k| {
+            let mut s = ring.zero();
+            for j in 0..=k {
+                if lhs[j].is_zero() {
+                    continue;
+                }
+                let mut t = lhs[j].clone();
+                t.mul_assign(&rhs[k - j]);
+                s.add_assign(&t);
+            }
+            s
+        }).collect()
+    }
+}
+
+// Neg
+impl<T: Ring, const N: usize> NegAssign for GenericJet<T, N> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        for c in self.coeffs.iter_mut() {
+            c.neg_assign();
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> Neg for GenericJet<T, N> {
+    type Output = GenericJet<T, N>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: Ring, const N: usize> Neg for &GenericJet<T, N> {
+    type Output = GenericJet<T, N>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl<T: Ring, const N: usize> AddAssign<&Self> for GenericJet<T, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        for (x, y) in self.coeffs.iter_mut().zip(rhs.coeffs.iter()) {
+            x.add_assign(y);
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> AddFrom<&Self> for GenericJet<T, N> {
+    #[inline]
+    fn add_from(&mut self, lhs: &Self) {
+        for (x, y) in self.coeffs.iter_mut().zip(lhs.coeffs.iter()) {
+            x.add_from(y);
+        }
+    }
+}
+
+forward_binop! {
+    GenericJet<T: Ring, const N: usize>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+impl<T: Ring, const N: usize> SubAssign<&Self> for GenericJet<T, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        for (x, y) in self.coeffs.iter_mut().zip(rhs.coeffs.iter()) {
+            x.sub_assign(y);
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> SubFrom<&Self> for GenericJet<T, N> {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        for (x, y) in self.coeffs.iter_mut().zip(lhs.coeffs.iter()) {
+            x.sub_from(y);
+        }
+    }
+}
+
+forward_binop! {
+    GenericJet<T: Ring, const N: usize>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+impl<T: Ring, const N: usize> MulAssign<&Self> for GenericJet<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.coeffs = Self::mul_truncated(&self.coeffs, &rhs.coeffs, &self.ctx.base_ring);
+    }
+}
+
+impl<T: Ring, const N: usize> MulFrom<&Self> for GenericJet<T, N> {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        self.coeffs = Self::mul_truncated(&lhs.coeffs, &self.coeffs, &self.ctx.base_ring);
+    }
+}
+
+forward_binop! {
+    GenericJet<T: Ring, const N: usize>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
@@ -0,0 +1,482 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::poly::{GenericPoly, GenericPolyRing};
+use crate::traits::InvertibleRing;
+use crate::util::{check_unital, ring_pow};
+
+mod ops;
+
+///////////////////////////////////////////////////////////////////
+// GenericLaurentPolyRing<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct GenericLaurentPolyCtx<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) poly_ring: GenericPolyRing<T>,
+}
+
+impl<T: Ring> GenericLaurentPolyCtx<T> {
+    pub fn new<V: Into<String>>(base_ring: T, var: V) -> Self {
+        GenericLaurentPolyCtx {
+            poly_ring: GenericPolyRing::init(&base_ring, var),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericLaurentPolyRing<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericLaurentPolyCtx<T>>,
+}
+
+impl<T: Ring> GenericLaurentPolyRing<T> {
+    /// Panics if the base ring has no identity, e.g. a non-square matrix
+    /// space.
+    #[inline]
+    pub fn init<S: Into<String>>(base_ring: &T, var: S) -> Self {
+        check_unital(base_ring);
+        GenericLaurentPolyRing {
+            ctx: Rc::new(GenericLaurentPolyCtx::new(base_ring.clone(), var))
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.poly_ring.base_ring()
+    }
+
+    /// The polynomial ring used to store the coefficients.
+    #[inline]
+    pub fn poly_ring(&self) -> &GenericPolyRing<T> {
+        &self.ctx.poly_ring
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.poly_ring.var()
+    }
+
+    #[inline]
+    pub fn set_var<S: Into<String>>(&mut self, var: S) {
+        self.ctx.poly_ring.ctx.var.replace(var.into());
+    }
+
+    /// The Laurent polynomial `x^val * poly`.
+    pub fn from_parts(&self, poly: GenericPoly<T>, val: i64) -> GenericLaurentPoly<T> {
+        let mut res = GenericLaurentPoly {
+            ctx: Rc::clone(&self.ctx),
+            poly,
+            val,
+        };
+        res.normalize();
+        res
+    }
+
+    /// The monomial `x^k`.
+    pub fn monomial(&self, k: i64) -> GenericLaurentPoly<T> {
+        self.from_parts(self.poly_ring().one(), k)
+    }
+
+    /// The generator `x`.
+    #[inline]
+    pub fn gen(&self) -> GenericLaurentPoly<T> {
+        self.monomial(1)
+    }
+
+    /// The inverse of the generator, `x^-1`.
+    #[inline]
+    pub fn gen_inverse(&self) -> GenericLaurentPoly<T> {
+        self.monomial(-1)
+    }
+}
+
+impl<T: Ring> NewElement<&GenericLaurentPoly<T>> for GenericLaurentPolyRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericLaurentPoly<T>) -> GenericLaurentPoly<T> {
+        val.clone()
+    }
+}
+
+impl<T: Ring> NewElement<&GenericPoly<T>> for GenericLaurentPolyRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericPoly<T>) -> GenericLaurentPoly<T> {
+        self.from_parts(val.clone(), 0)
+    }
+}
+
+impl<T: Ring> NewElement<GenericPoly<T>> for GenericLaurentPolyRing<T> {
+    #[inline]
+    fn new(&self, val: GenericPoly<T>) -> GenericLaurentPoly<T> {
+        self.from_parts(val, 0)
+    }
+}
+
+impl<S, T, const CAP: usize> NewElement<[S; CAP]> for GenericLaurentPolyRing<T>
+where
+    T: Ring + NewElement<S>,
+{
+    #[inline]
+    fn new(&self, coeffs: [S; CAP]) -> GenericLaurentPoly<T> {
+        self.from_parts(self.poly_ring().new(coeffs), 0)
+    }
+}
+
+impl<S, T> NewElement<Vec<S>> for GenericLaurentPolyRing<T>
+where
+    T: Ring<Element=S>
+{
+    #[inline]
+    fn new(&self, coeffs: Vec<S>) -> GenericLaurentPoly<T> {
+        self.from_parts(self.poly_ring().new(coeffs), 0)
+    }
+}
+
+impl<T: Ring + fmt::Display> fmt::Display for GenericLaurentPolyRing<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Univariate Laurent polynomial ring in {} over {}",
+            self.var(),
+            self.base_ring()
+        )
+    }
+}
+
+impl<T: Ring + PartialEq> Eq for GenericLaurentPolyRing<T> {}
+
+impl<T: Ring + PartialEq> PartialEq for GenericLaurentPolyRing<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericLaurentPolyRing<T>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) ||
+            self.base_ring() == rhs.base_ring()
+    }
+}
+
+impl<T: Ring + Hash> Hash for GenericLaurentPolyRing<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base_ring().hash(state);
+    }
+}
+
+impl<T: Ring> Parent for GenericLaurentPolyRing<T> {
+    type Element = GenericLaurentPoly<T>;
+}
+
+impl<T: Ring> Identity<Additive> for GenericLaurentPolyRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericLaurentPoly<T> {
+        self.from_parts(self.poly_ring().zero(), 0)
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for GenericLaurentPolyRing<T> {}
+
+impl<T: Ring> Associative<Additive> for GenericLaurentPolyRing<T> {}
+
+impl<T: Ring> Commutative<Additive> for GenericLaurentPolyRing<T> {}
+
+impl<T: Ring> Identity<Multiplicative> for GenericLaurentPolyRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericLaurentPoly<T> {
+        self.monomial(0)
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for GenericLaurentPolyRing<T> {}
+
+impl<T: Ring> Commutative<Multiplicative> for GenericLaurentPolyRing<T> {}
+
+impl<T: Ring> Distributive for GenericLaurentPolyRing<T> {}
+
+/// The units detected are the monomials `c*x^k` with `c` a unit, which are
+/// all of the units when the base ring is an integral domain.
+impl<T: InvertibleRing> InvertibleRing for GenericLaurentPolyRing<T> {
+    #[inline]
+    fn inverse(&self, a: &GenericLaurentPoly<T>) -> Option<GenericLaurentPoly<T>> {
+        a.inv()
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// GenericLaurentPoly<T>
+///////////////////////////////////////////////////////////////////
+
+/// A Laurent polynomial `x^val * poly`. Unless it is zero, `poly` has a
+/// nonzero constant term, so `val` is the valuation.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericLaurentPoly<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericLaurentPolyCtx<T>>,
+    pub(crate) poly: GenericPoly<T>,
+    pub(crate) val: i64,
+}
+
+impl<T: Ring> fmt::Display for GenericLaurentPoly<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let x = self.var();
+        if self.poly.valuation().is_none() {
+            return write!(f, "{}", self.poly.coefficients()[0]);
+        }
+
+        let mut out = Vec::with_capacity(self.poly.len());
+        for (i, c) in self.poly.terms() {
+            let e = self.val + i as i64;
+            if e == 0 {
+                out.push(format!("{}", c));
+            } else {
+                let m = if e == 1 { x.clone() } else { format!("{}^{}", x, e) };
+                if c.is_one() {
+                    out.push(m);
+                } else {
+                    out.push(format!("{}*{}", c, m));
+                }
+            }
+        }
+        out.reverse();
+        write!(f, "{}", out.join(" + "))
+    }
+}
+
+impl<T: Ring> Eq for GenericLaurentPoly<T> {}
+
+impl<T: Ring> PartialEq for GenericLaurentPoly<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericLaurentPoly<T>) -> bool {
+        self.val == rhs.val && self.poly == rhs.poly
+    }
+}
+
+impl<T: Ring + Hash> Hash for GenericLaurentPoly<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent().hash(state);
+        self.val.hash(state);
+        self.poly.coefficients().hash(state);
+    }
+}
+
+impl<T: Ring> Element for GenericLaurentPoly<T> {
+    type Parent = GenericLaurentPolyRing<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericLaurentPolyRing<T> {
+        GenericLaurentPolyRing {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: Ring> Operation<Additive> for GenericLaurentPoly<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for GenericLaurentPoly<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.poly.valuation().is_none()
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for GenericLaurentPoly<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: Ring> Operation<Multiplicative> for GenericLaurentPoly<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for GenericLaurentPoly<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.val == 0 && self.poly.len() == 1 && self.poly.coefficients()[0].is_one()
+    }
+}
+
+impl<T: Ring> GenericLaurentPoly<T> {
+    // move the valuation of poly into val and strip trailing zeros
+    pub(crate) fn normalize(&mut self) {
+        self.poly.normalize();
+        match self.poly.valuation() {
+            Some(v) => {
+                self.poly = self.poly.shift_right(v);
+                self.val += v as i64;
+            },
+            None => self.val = 0,
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.poly_ring.base_ring()
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.poly_ring.var()
+    }
+
+    /// The valuation, or `None` for zero.
+    #[inline]
+    pub fn valuation(&self) -> Option<i64> {
+        self.poly.valuation().map(|_| self.val)
+    }
+
+    /// The degree, or `None` for zero.
+    #[inline]
+    pub fn degree(&self) -> Option<i64> {
+        self.poly.valuation().map(|_| self.val + self.poly.len() as i64 - 1)
+    }
+
+    /// The coefficient of `x^i`.
+    pub fn get_coefficient(&self, i: i64) -> Elem<T> {
+        if i < self.val {
+            self.base_ring().zero()
+        } else {
+            self.poly.get_coefficient((i - self.val) as usize)
+        }
+    }
+
+    /// Set the coefficient of `x^i`.
+    pub fn set_coefficient(&mut self, i: i64, coeff: Elem<T>) {
+        if self.poly.valuation().is_none() {
+            self.val = i;
+        } else if i < self.val {
+            self.poly = self.poly.shift_left((self.val - i) as usize);
+            self.val = i;
+        }
+        self.poly.set_coefficient((i - self.val) as usize, coeff);
+        self.normalize();
+    }
+
+    /// Iterate over the nonzero terms as `(exponent, coefficient)` pairs, in
+    /// increasing order of exponent.
+    #[inline]
+    pub fn terms(&self) -> impl Iterator<Item = (i64, &Elem<T>)> {
+        let val = self.val;
+        self.poly.terms().map(move |(i, c)| (val + i as i64, c))
+    }
+
+    /// The polynomial `poly` such that `self = x^valuation * poly`.
+    #[inline]
+    pub fn unit_part(&self) -> &GenericPoly<T> {
+        &self.poly
+    }
+
+    /// Convert to a polynomial, if there are no negative powers of `x`.
+    pub fn to_poly(&self) -> Option<GenericPoly<T>> {
+        if self.val < 0 {
+            None
+        } else {
+            Some(self.poly.shift_left(self.val as usize))
+        }
+    }
+
+    /// Multiply by `x^k`.
+    #[inline]
+    pub fn shift(&self, k: i64) -> Self {
+        let mut res = self.clone();
+        if res.poly.valuation().is_some() {
+            res.val += k;
+        }
+        res
+    }
+
+    /// Raise to the power `e` by repeated squaring.
+    pub fn pow_ui(&self, e: u64) -> Self {
+        let mut res = self.parent().monomial(0);
+        for i in (0..64 - e.leading_zeros()).rev() {
+            res = &res * &res;
+            if (e >> i) & 1 == 1 {
+                res = &res * self;
+            }
+        }
+        res
+    }
+}
+
+impl<T: InvertibleRing> GenericLaurentPoly<T> {
+    /// The inverse, if `self` is a monomial with unit coefficient.
+    pub fn inv(&self) -> Option<Self> {
+        if self.poly.len() != 1 {
+            return None;
+        }
+        let c = self.base_ring().inverse(&self.poly.coefficients()[0])?;
+        let poly = self.ctx.poly_ring.new(vec![c]);
+        Some(self.parent().from_parts(poly, -self.val))
+    }
+
+    /// Evaluate at `a`. Returns `None` if there are negative powers of `x`
+    /// and `a` is not a unit.
+    pub fn evaluate(&self, a: &Elem<T>) -> Option<Elem<T>> {
+        let mut res = self.poly.evaluate(a);
+        let b = if self.val < 0 {
+            self.base_ring().inverse(a)?
+        } else {
+            a.clone()
+        };
+        res.mul_assign(&ring_pow(self.base_ring(), &b, self.val.unsigned_abs().into()));
+        Some(res)
+    }
+}
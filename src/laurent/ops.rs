@@ -0,0 +1,140 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::laurent::GenericLaurentPoly;
+use crate::poly::GenericPoly;
+
+impl<T: Ring> GenericLaurentPoly<T> {
+    // the underlying polynomials of self and rhs over a common power of x
+    fn aligned(&self, rhs: &Self) -> (GenericPoly<T>, GenericPoly<T>, i64) {
+        let v = std::cmp::min(self.val, rhs.val);
+        let a = self.poly.shift_left((self.val - v) as usize);
+        let b = rhs.poly.shift_left((rhs.val - v) as usize);
+        (a, b, v)
+    }
+}
+
+// Neg
+impl<T: Ring> NegAssign for GenericLaurentPoly<T> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.poly.neg_assign();
+    }
+}
+
+impl<T: Ring> Neg for GenericLaurentPoly<T> {
+    type Output = GenericLaurentPoly<T>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: Ring> Neg for &GenericLaurentPoly<T> {
+    type Output = GenericLaurentPoly<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl<T: Ring> AddAssign<&GenericLaurentPoly<T>> for GenericLaurentPoly<T> {
+    fn add_assign(&mut self, rhs: &GenericLaurentPoly<T>) {
+        let (mut a, b, v) = self.aligned(rhs);
+        a.add_assign(&b);
+        self.poly = a;
+        self.val = v;
+        self.normalize();
+    }
+}
+
+impl<T: Ring> AddFrom<&GenericLaurentPoly<T>> for GenericLaurentPoly<T> {
+    fn add_from(&mut self, lhs: &GenericLaurentPoly<T>) {
+        let (mut a, b, v) = self.aligned(lhs);
+        a.add_from(&b);
+        self.poly = a;
+        self.val = v;
+        self.normalize();
+    }
+}
+
+forward_binop! {
+    GenericLaurentPoly<T: Ring>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+impl<T: Ring> SubAssign<&Self> for GenericLaurentPoly<T> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        let (mut a, b, v) = self.aligned(rhs);
+        a.sub_assign(&b);
+        self.poly = a;
+        self.val = v;
+        self.normalize();
+    }
+}
+
+impl<T: Ring> SubFrom<&Self> for GenericLaurentPoly<T> {
+    fn sub_from(&mut self, lhs: &Self) {
+        let (mut a, b, v) = self.aligned(lhs);
+        a.sub_from(&b);
+        self.poly = a;
+        self.val = v;
+        self.normalize();
+    }
+}
+
+forward_binop! {
+    GenericLaurentPoly<T: Ring>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+
+impl<T: Ring> MulAssign<&Self> for GenericLaurentPoly<T> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.poly.mul_assign(&rhs.poly);
+        self.val += rhs.val;
+        self.normalize();
+    }
+}
+
+impl<T: Ring> MulFrom<&Self> for GenericLaurentPoly<T> {
+    fn mul_from(&mut self, lhs: &Self) {
+        self.poly.mul_from(&lhs.poly);
+        self.val += lhs.val;
+        self.normalize();
+    }
+}
+
+forward_binop! {
+    GenericLaurentPoly<T: Ring>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
@@ -17,7 +17,19 @@
 
 #[macro_use]
 mod macros;
+mod util;
 
+pub mod integer;
 pub mod poly;
 pub mod mat;
+pub mod laurent;
+pub mod frac;
+pub mod series;
+pub mod quotient;
+pub mod finite_field;
+pub mod zmod;
+pub mod prime_field;
+pub mod jet;
+pub mod quad;
+pub mod traits;
 
@@ -152,5 +152,119 @@ macro_rules! forward_binop {
             }
         }
     */
-    }
+    };
+    (
+        $ident:ident
+        $op:ident, $meth:ident
+        $op_assign:ident, $meth_assign:ident
+        $op_from:ident, $meth_from:ident
+    ) => {
+        impl $op for $ident {
+            type Output = $ident;
+            #[inline]
+            fn $meth(mut self, rhs: Self) -> Self::Output {
+                self.$meth_assign(rhs);
+                self
+            }
+        }
+
+        impl $op<&$ident> for $ident {
+            type Output = $ident;
+            #[inline]
+            fn $meth(mut self, rhs: &Self) -> Self::Output {
+                self.$meth_assign(rhs);
+                self
+            }
+        }
+
+        impl $op<$ident> for &$ident {
+            type Output = $ident;
+            #[inline]
+            fn $meth(self, mut rhs: $ident) -> Self::Output {
+                rhs.$meth_from(self);
+                rhs
+            }
+        }
+
+        impl $op<&$ident> for &$ident {
+            type Output = $ident;
+            #[inline]
+            fn $meth(self, rhs: &$ident) -> Self::Output {
+                let mut res = self.clone();
+                res.$meth_assign(rhs);
+                res
+            }
+        }
+
+        impl $op_assign<$ident> for $ident {
+            #[inline]
+            fn $meth_assign(&mut self, rhs: $ident) {
+                self.$meth_assign(&rhs);
+            }
+        }
+
+        impl $op_from<$ident> for $ident {
+            #[inline]
+            fn $meth_from(&mut self, rhs: $ident) {
+                self.$meth_from(&rhs);
+            }
+        }
+    };
+    (
+        $ident:ident<$gen:ident : $bound:ident, const $n:ident : usize>
+        $op:ident, $meth:ident
+        $op_assign:ident, $meth_assign:ident
+        $op_from:ident, $meth_from:ident
+    ) => {
+        impl<T: $bound, const $n: usize> $op for $ident<T, $n> {
+            type Output = $ident<T, $n>;
+            #[inline]
+            fn $meth(mut self, rhs: Self) -> Self::Output {
+                self.$meth_assign(rhs);
+                self
+            }
+        }
+
+        impl<T: $bound, const $n: usize> $op<&$ident<T, $n>> for $ident<T, $n> {
+            type Output = $ident<T, $n>;
+            #[inline]
+            fn $meth(mut self, rhs: &Self) -> Self::Output {
+                self.$meth_assign(rhs);
+                self
+            }
+        }
+
+        impl<T: $bound, const $n: usize> $op<$ident<T, $n>> for &$ident<T, $n> {
+            type Output = $ident<T, $n>;
+            #[inline]
+            fn $meth(self, mut rhs: $ident<T, $n>) -> Self::Output {
+                rhs.$meth_from(self);
+                rhs
+            }
+        }
+
+        impl<T: $bound, const $n: usize> $op<&$ident<T, $n>> for &$ident<T, $n> {
+            type Output = $ident<T, $n>;
+            #[inline]
+            fn $meth(self, rhs: &$ident<T, $n>) -> Self::Output {
+                let mut res = self.clone();
+                res.$meth_assign(rhs);
+                res
+            }
+        }
+
+        impl<T: $bound, const $n: usize> $op_assign<$ident<T, $n>> for $ident<T, $n> {
+            #[inline]
+            fn $meth_assign(&mut self, rhs: $ident<T, $n>) {
+                self.$meth_assign(&rhs);
+            }
+        }
+
+        impl<T: $bound, const $n: usize> $op_from<$ident<T, $n>> for $ident<T, $n> {
+            #[inline]
+            fn $meth_from(&mut self, rhs: $ident<T, $n>) {
+                self.$meth_from(&rhs);
+            }
+        }
+    };
 }
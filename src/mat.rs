@@ -17,6 +17,8 @@
 
 
 use inertia_algebra::*;
+use std::cell::Cell;
+use std::error::Error;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
@@ -24,7 +26,70 @@ use std::rc::Rc;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::util::check_unital;
+
+mod charpoly;
+mod det;
+mod echelon;
+mod frobenius;
+mod lu;
+mod mul;
 mod ops;
+mod solve;
+
+pub(crate) use charpoly::berkowitz;
+pub use charpoly::charpoly_hessenberg;
+pub(crate) use det::bareiss_with;
+pub use det::{det_bareiss, det_berkowitz, det_gaussian};
+pub use echelon::Rref;
+pub use frobenius::FrobeniusForm;
+pub use lu::{FractionFreeLu, Plu};
+pub use solve::Solution;
+
+/// The default size of the blocks in cache-blocked multiplication.
+pub const DEFAULT_BLOCK_SIZE: usize = 64;
+
+/// The default dimension above which multiplication uses Strassen-Winograd
+/// recursion.
+pub const DEFAULT_STRASSEN_THRESHOLD: usize = 128;
+
+/// Errors raised by matrix operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatError {
+    /// The operands have incompatible dimensions, given as `(nrows, ncols)`.
+    DimensionMismatch {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    /// The operation needs a square matrix.
+    NotSquare {
+        nrows: usize,
+        ncols: usize,
+    },
+    /// The matrix is not invertible.
+    Singular,
+    /// The linear system has no solution.
+    Inconsistent,
+}
+
+impl fmt::Display for MatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatError::DimensionMismatch { lhs, rhs } =>
+                write!(
+                    f,
+                    "incompatible matrix dimensions {}x{} and {}x{}",
+                    lhs.0, lhs.1, rhs.0, rhs.1
+                ),
+            MatError::NotSquare { nrows, ncols } =>
+                write!(f, "matrix of dimensions {}x{} is not square", nrows, ncols),
+            MatError::Singular => write!(f, "matrix is singular"),
+            MatError::Inconsistent => write!(f, "linear system has no solution"),
+        }
+    }
+}
+
+impl Error for MatError {}
 
 ///////////////////////////////////////////////////////////////////
 // GenericMatSpace<T>
@@ -42,12 +107,20 @@ pub(crate) struct GenericMatCtx<T> {
     )]
     pub(crate) base_ring: T,
     pub(crate) nrows: u64,
-    pub(crate) ncols: u64
+    pub(crate) ncols: u64,
+    pub(crate) block_size: Cell<usize>,
+    pub(crate) strassen_threshold: Cell<usize>,
 }
 
 impl<T> GenericMatCtx<T> {
     pub fn new(base_ring: T, nrows: u64, ncols: u64) -> Self {
-        GenericMatCtx { base_ring, nrows, ncols }
+        GenericMatCtx {
+            base_ring,
+            nrows,
+            ncols,
+            block_size: Cell::new(DEFAULT_BLOCK_SIZE),
+            strassen_threshold: Cell::new(DEFAULT_STRASSEN_THRESHOLD),
+        }
     }
 }
 
@@ -64,6 +137,121 @@ pub struct GenericMatSpace<T> {
     pub(crate) ctx: Rc<GenericMatCtx<T>>,
 }
 
+impl<T: Ring> GenericMatSpace<T> {
+    /// The zero matrix.
+    #[inline]
+    pub fn zero(&self) -> GenericMat<T> {
+        let len = self.nrows() * self.ncols();
+        GenericMat {
+            entries: vec![self.base_ring().zero(); len],
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+
+    /// The identity matrix. Panics if the space is not square.
+    pub fn one(&self) -> GenericMat<T> {
+        if !self.is_square() {
+            let e = MatError::NotSquare {
+                nrows: self.nrows(),
+                ncols: self.ncols(),
+            };
+            panic!("{}", e);
+        }
+        let n = self.nrows();
+        let mut res = self.zero();
+        for i in 0..n {
+            res.entries[i*n + i] = self.base_ring().one();
+        }
+        res
+    }
+
+    /// Whether the matrices are square, making the space a ring. Polynomial
+    /// rings, matrix spaces and other structures over a non-square space
+    /// panic when they are constructed.
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.nrows() == self.ncols()
+    }
+
+    /// The size of the blocks in cache-blocked multiplication.
+    #[inline]
+    pub fn block_size(&self) -> usize {
+        self.ctx.block_size.get()
+    }
+
+    /// Set the block size, which must be positive, for products computed in
+    /// this space. The setting lives in the context shared by all clones of
+    /// the space and all of its matrices, so it affects each of them, and
+    /// spaces derived from it for results of other shapes inherit it.
+    #[inline]
+    pub fn set_block_size(&self, size: usize) {
+        assert!(size > 0, "Block size must be positive.");
+        self.ctx.block_size.set(size);
+    }
+
+    /// The dimension above which multiplication uses Strassen-Winograd
+    /// recursion.
+    #[inline]
+    pub fn strassen_threshold(&self) -> usize {
+        self.ctx.strassen_threshold.get()
+    }
+
+    /// Set the Strassen-Winograd threshold for products computed in this
+    /// space. Use `usize::MAX` to disable the recursion. Like the block size,
+    /// it is shared by all clones of the space and all of its matrices.
+    #[inline]
+    pub fn set_strassen_threshold(&self, n: usize) {
+        self.ctx.strassen_threshold.set(std::cmp::max(n, 1));
+    }
+
+    /// The matrix with the given entries in row-major order. Panics if the
+    /// number of entries is not `nrows*ncols`.
+    pub fn from_entries(&self, entries: Vec<Elem<T>>) -> GenericMat<T> {
+        assert_eq!(
+            entries.len(),
+            self.nrows() * self.ncols(),
+            "Number of entries does not match the matrix dimensions."
+        );
+        GenericMat {
+            entries,
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: Ring> NewElement<&GenericMat<T>> for GenericMatSpace<T> {
+    #[inline]
+    fn new(&self, val: &GenericMat<T>) -> GenericMat<T> {
+        val.clone()
+    }
+}
+
+/// Panics if the dimensions do not match the space.
+impl<S, T, const R: usize, const C: usize> NewElement<[[S; C]; R]> for GenericMatSpace<T>
+where
+    T: Ring + NewElement<S>,
+{
+    fn new(&self, rows: [[S; C]; R]) -> GenericMat<T> {
+        let entries = rows.into_iter()
+            .flat_map(|row| row.into_iter())
+            .map(|x| self.base_ring().new(x))
+            .collect();
+        assert_eq!(R, self.nrows(), "Number of rows does not match the matrix space.");
+        self.from_entries(entries)
+    }
+}
+
+/// Entries in row-major order. Panics if the length is not `nrows*ncols`.
+impl<S, T> NewElement<Vec<S>> for GenericMatSpace<T>
+where
+    T: Ring<Element=S>
+{
+    #[inline]
+    fn new(&self, entries: Vec<S>) -> GenericMat<T> {
+        self.from_entries(entries)
+    }
+}
+
 impl<T: Ring + fmt::Display> fmt::Display for GenericMatSpace<T> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -119,12 +307,7 @@ impl<T: Ring> Parent for GenericMatSpace<T> {
 impl<T: Ring> Identity<Additive> for GenericMatSpace<T> {
     #[inline]
     fn identity(&self) -> GenericMat<T> {
-        unimplemented!()
-        /* TODO
-        GenericMat {
-            entries: vec![self.base_ring().zero()],
-            ctx: Rc::clone(&self.ctx),
-        }*/
+        self.zero()
     }
 }
 
@@ -134,11 +317,27 @@ impl<T: Ring> Associative<Additive> for GenericMatSpace<T> {}
 
 impl<T: Ring> Commutative<Additive> for GenericMatSpace<T> {}
 
+/// Panics if the space is not square. Structures over a matrix space check
+/// this when they are constructed.
+impl<T: Ring> Identity<Multiplicative> for GenericMatSpace<T> {
+    #[inline]
+    fn identity(&self) -> GenericMat<T> {
+        self.one()
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for GenericMatSpace<T> {}
+
+impl<T: Ring> Distributive for GenericMatSpace<T> {}
+
 impl<T: Ring> MatrixSpace<T> for GenericMatSpace<T> {
     type Element = GenericMat<T>;
 
+    /// Panics if the base ring has no identity, e.g. a non-square matrix
+    /// space.
     #[inline]
     fn init<D: Into<u64>>(base_ring: &T, nrows: D, ncols: D) -> Self {
+        check_unital(base_ring);
         GenericMatSpace {
             ctx: Rc::new(
                 GenericMatCtx::new(
@@ -204,7 +403,7 @@ where
             for j in 0..n {
                 row.push(format!(" {} ", entries[i*n+j]));
             }
-            if i == m - 1 {
+            if i + 1 == m {
                 row.push("]".to_string());
             } else {
                 row.push("]\n".to_string());
@@ -231,16 +430,7 @@ where
         if rhs.ncols() != ncols {
             return false;
         }
-        let len = nrows * ncols - 1;
-
-        let e1 = &self.entries;
-        let e2 = &rhs.entries;
-        for i in 0..len {
-            if e1[i] != e2[i] {
-                return false;
-            }
-        }
-        true
+        self.entries.iter().zip(rhs.entries.iter()).all(|(a, b)| a == b)
     }
 }
 
@@ -286,20 +476,40 @@ impl<T: Ring> Element for GenericMat<T> {
 }
 
 impl<T: Ring> Operation<Additive> for GenericMat<T> {
-    fn operate(&self, _right: &Self) -> Self {
-        unimplemented!()
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
     }
 }
 
 impl<T: Ring> IsIdentity<Additive> for GenericMat<T> {
+    #[inline]
     fn is_identity(&self) -> bool {
-        unimplemented!()
+        self.entries.iter().all(|x| x.is_zero())
     }
 }
 
 impl<T: Ring> TwoSidedInverse<Additive> for GenericMat<T> {
+    #[inline]
     fn two_sided_inverse(&self) -> Self {
-        unimplemented!()
+        -self
+    }
+}
+
+/// Panics if the dimensions are incompatible.
+impl<T: Ring> Operation<Multiplicative> for GenericMat<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for GenericMat<T> {
+    fn is_identity(&self) -> bool {
+        let n = self.ncols();
+        self.is_square() && self.entries.iter().enumerate().all(|(k, x)| {
+            if k / n == k % n { x.is_one() } else { x.is_zero() }
+        })
     }
 }
 
@@ -327,19 +537,21 @@ impl<T: Ring> MatrixSpaceElement<T> for GenericMat<T> {
     }
 
     #[inline]
-    fn get_entry(&self, _i: usize, _j: usize) -> Option<Elem<T>> {
-        unimplemented!()
-        //self.entries.get(i*self.ncols() + j)
+    fn get_entry(&self, i: usize, j: usize) -> Option<Elem<T>> {
+        if i < self.nrows() && j < self.ncols() {
+            Some(self.entries[i*self.ncols() + j].clone())
+        } else {
+            None
+        }
     }
 
     #[inline]
     fn set_entry(&mut self, i: usize, j: usize, entry: Elem<T>) -> Option<Elem<T>> {
-        let idx = i*self.ncols() + j;
-        if idx >= self.len() {
-            None
+        if i < self.nrows() && j < self.ncols() {
+            let idx = i*self.ncols() + j;
+            Some(std::mem::replace(&mut self.entries[idx], entry))
         } else {
-            self.entries.push(entry);
-            Some(self.entries.swap_remove(idx))
+            None
         }
     }
 
@@ -378,3 +590,73 @@ impl<T: Ring> MatrixSpaceElement<T> for GenericMat<T> {
     }
     */
 }
+
+impl<T: Ring> GenericMat<T> {
+    /// The entry in row `i` and column `j`. Panics if out of range.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> &Elem<T> {
+        assert!(i < self.nrows() && j < self.ncols(), "Index out of range.");
+        &self.entries[i*self.ncols() + j]
+    }
+
+    /// The entry in row `i` and column `j`. Panics if out of range.
+    #[inline]
+    pub fn entry_mut(&mut self, i: usize, j: usize) -> &mut Elem<T> {
+        assert!(i < self.nrows() && j < self.ncols(), "Index out of range.");
+        let n = self.ncols();
+        &mut self.entries[i*n + j]
+    }
+
+    /// The entries in row-major order.
+    #[inline]
+    pub fn entries(&self) -> &Vec<Elem<T>> {
+        &self.entries
+    }
+
+    #[inline]
+    pub fn into_entries(self) -> Vec<Elem<T>> {
+        self.entries
+    }
+
+    /// The transpose.
+    pub fn transpose(&self) -> Self {
+        let (m, n) = (self.nrows(), self.ncols());
+        let mut entries = Vec::with_capacity(m*n);
+        for j in 0..n {
+            entries.extend((0..m).map(|i| self.entries[i*n + j].clone()));
+        }
+        GenericMat {
+            ctx: self.ctx_with_dims(n, m),
+            entries,
+        }
+    }
+
+    /// Whether the matrix is square.
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.nrows() == self.ncols()
+    }
+
+    // the context of nrows by ncols matrices, which is that of self if it
+    // has these dimensions and otherwise a new one with the same settings
+    pub(crate) fn ctx_with_dims(&self, nrows: usize, ncols: usize) -> Rc<GenericMatCtx<T>> {
+        if self.nrows() == nrows && self.ncols() == ncols {
+            return Rc::clone(&self.ctx);
+        }
+        let ctx = GenericMatCtx::new(self.base_ring().clone(), nrows as u64, ncols as u64);
+        ctx.block_size.set(self.ctx.block_size.get());
+        ctx.strassen_threshold.set(self.ctx.strassen_threshold.get());
+        Rc::new(ctx)
+    }
+
+    // panics with MatError::NotSquare unless the matrix is square
+    pub(crate) fn check_square(&self) {
+        if !self.is_square() {
+            let e = MatError::NotSquare {
+                nrows: self.nrows(),
+                ncols: self.ncols(),
+            };
+            panic!("{}", e);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use crate::mat::GenericMat;
+use crate::poly::{GenericPoly, GenericPolyRing};
+use crate::traits::{DetRing, DivisionRing};
+
+// The coefficients of the characteristic polynomial det(x*I - A) of the n by n
+// matrix A with row-major entries `a`, in increasing order of degree, by
+// Berkowitz's division free algorithm.
+pub(crate) fn berkowitz<T: Ring + ?Sized>(ring: &T, a: &[Elem<T>], n: usize) -> Vec<Elem<T>> {
+    // coefficients of the characteristic polynomial of the leading r by r
+    // submatrix, in decreasing order of degree
+    let mut p = vec![ring.one()];
+
+    for r in 0..n {
+        // the Toeplitz column [1, -a_rr, -R*C, -R*A*C, ..., -R*A^(r-1)*C]
+        // where R and C are the row and column bordering the submatrix
+        let mut t = Vec::with_capacity(r + 2);
+        t.push(ring.one());
+        let mut d = a[r*n + r].clone();
+        d.neg_assign();
+        t.push(d);
+
+        let mut v: Vec<Elem<T>> = (0..r).map(|i| a[i*n + r].clone()).collect();
+        for k in 0..r {
+            let mut s = ring.zero();
+            for (j, x) in v.iter().enumerate() {
+                let mut u = a[r*n + j].clone();
+                u.mul_assign(x);
+                s.add_assign(&u);
+            }
+            s.neg_assign();
+            t.push(s);
+
+            if k + 1 < r {
+                v = (0..r).map(|i| {
+                    let mut s = ring.zero();
+                    for (j, x) in v.iter().enumerate() {
+                        let mut u = a[i*n + j].clone();
+                        u.mul_assign(x);
+                        s.add_assign(&u);
+                    }
+                    s
+                }).collect();
+            }
+        }
+
+        // multiply by the lower triangular Toeplitz matrix with first column t
+        p = (0..r + 2).map(|i| {
+            let mut s = ring.zero();
+            for (j, c) in p.iter().enumerate().take(i + 1) {
+                let mut u = t[i - j].clone();
+                u.mul_assign(c);
+                s.add_assign(&u);
+            }
+            s
+        }).collect();
+    }
+
+    p.reverse();
+    p
+}
+
+/// The coefficients of the characteristic polynomial `det(x*I - A)` of the
+/// `n` by `n` matrix `A` with row-major entries `a`, in increasing order of
+/// degree, by reduction to Hessenberg form over a field.
+pub fn charpoly_hessenberg<T: DivisionRing + ?Sized>(
+    ring: &T,
+    a: &[Elem<T>],
+    n: usize
+) -> Vec<Elem<T>> {
+    let mut h = a.to_vec();
+
+    // reduce to upper Hessenberg form by similarity transforms, clearing
+    // column k below the subdiagonal
+    for k in 0..n.saturating_sub(2) {
+        let i = match (k + 1..n).find(|&i| !h[i*n + k].is_zero()) {
+            Some(i) => i,
+            None => continue,
+        };
+        if i != k + 1 {
+            for j in 0..n {
+                h.swap(i*n + j, (k + 1)*n + j);
+            }
+            for j in 0..n {
+                h.swap(j*n + i, j*n + k + 1);
+            }
+        }
+        let inv = ring.inverse(&h[(k + 1)*n + k]).expect("Nonzero element is not a unit.");
+        for r in k + 2..n {
+            if h[r*n + k].is_zero() {
+                continue;
+            }
+            let mut c = h[r*n + k].clone();
+            c.mul_assign(&inv);
+            // row r -= c*row (k + 1), then column (k + 1) += c*column r
+            for j in k..n {
+                let mut t = c.clone();
+                t.mul_assign(&h[(k + 1)*n + j]);
+                h[r*n + j].sub_assign(&t);
+            }
+            for j in 0..n {
+                let mut t = h[j*n + r].clone();
+                t.mul_assign(&c);
+                h[j*n + k + 1].add_assign(&t);
+            }
+        }
+    }
+
+    // p[m] is the characteristic polynomial of the leading m by m submatrix,
+    // in increasing order of degree
+    let mut p: Vec<Vec<Elem<T>>> = vec![vec![ring.one()]];
+    for m in 1..=n {
+        // (x - h_mm)*p[m - 1]
+        let mut q = vec![ring.zero(); m + 1];
+        for (j, c) in p[m - 1].iter().enumerate() {
+            q[j + 1].add_assign(c);
+            let mut t = h[(m - 1)*n + m - 1].clone();
+            t.mul_assign(c);
+            q[j].sub_assign(&t);
+        }
+        // minus t_i*h_(m-i),m*p[m - i - 1], with t_i the product of the
+        // subdiagonal entries from row m - i + 1 to m
+        let mut t = ring.one();
+        for i in 1..m {
+            t.mul_assign(&h[(m - i)*n + m - i - 1]);
+            if t.is_zero() {
+                break;
+            }
+            let mut u = t.clone();
+            u.mul_assign(&h[(m - i - 1)*n + m - 1]);
+            for (j, c) in p[m - i - 1].iter().enumerate() {
+                let mut v = u.clone();
+                v.mul_assign(c);
+                q[j].sub_assign(&v);
+            }
+        }
+        p.push(q);
+    }
+
+    p.swap_remove(n)
+}
+
+impl<T: Ring> GenericMat<T> {
+    /// The characteristic polynomial `det(x*I - self)` in `ring` by
+    /// Berkowitz's division free algorithm, valid over any commutative ring.
+    /// Panics if the matrix is not square.
+    pub fn charpoly_berkowitz(&self, ring: &GenericPolyRing<T>) -> GenericPoly<T> {
+        self.check_square();
+        let mut res = ring.new(berkowitz(self.base_ring(), &self.entries, self.nrows()));
+        res.normalize();
+        res
+    }
+}
+
+impl<T: DivisionRing> GenericMat<T> {
+    /// The characteristic polynomial `det(x*I - self)` in `ring` by reduction
+    /// to Hessenberg form over a field. Panics if the matrix is not square.
+    pub fn charpoly_hessenberg(&self, ring: &GenericPolyRing<T>) -> GenericPoly<T> {
+        self.check_square();
+        let coeffs = charpoly_hessenberg(self.base_ring(), &self.entries, self.nrows());
+        let mut res = ring.new(coeffs);
+        res.normalize();
+        res
+    }
+}
+
+impl<T: DetRing> GenericMat<T> {
+    /// The characteristic polynomial `det(x*I - self)` in a new polynomial
+    /// ring over the base ring in `x`. Panics if the matrix is not square.
+    #[inline]
+    pub fn charpoly(&self) -> GenericPoly<T> {
+        self.charpoly_in(&GenericPolyRing::init(self.base_ring(), "x"))
+    }
+
+    /// The characteristic polynomial `det(x*I - self)` in `ring`, by the
+    /// algorithm the base ring prefers: Hessenberg reduction over fields and
+    /// Berkowitz's algorithm otherwise. Panics if the matrix is not square.
+    pub fn charpoly_in(&self, ring: &GenericPolyRing<T>) -> GenericPoly<T> {
+        self.check_square();
+        let mut res = ring.new(self.base_ring().charpoly(&self.entries, self.nrows()));
+        res.normalize();
+        res
+    }
+}
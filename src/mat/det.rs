@@ -0,0 +1,170 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use crate::mat::{berkowitz, GenericMat};
+use crate::traits::{DetRing, DivisionRing, ExactDivisionRing};
+
+/// The determinant of the `n` by `n` matrix with row-major entries `a` by
+/// Berkowitz's division free algorithm, valid over any commutative ring.
+pub fn det_berkowitz<T: Ring + ?Sized>(ring: &T, a: &[Elem<T>], n: usize) -> Elem<T> {
+    // the constant coefficient of det(x*I - A) is (-1)^n*det(A)
+    let mut d = berkowitz(ring, a, n).swap_remove(0);
+    if n % 2 == 1 {
+        d.neg_assign();
+    }
+    d
+}
+
+/// The determinant of the `n` by `n` matrix with row-major entries `a` by
+/// fraction-free Bareiss elimination, valid over integral domains.
+#[inline]
+pub fn det_bareiss<T: ExactDivisionRing + ?Sized>(
+    ring: &T,
+    a: &[Elem<T>],
+    n: usize
+) -> Elem<T> {
+    bareiss_with(ring, a, n, |x, y| ring.divexact(x, y))
+}
+
+// Bareiss elimination with the exact division divexact of an integral domain
+pub(crate) fn bareiss_with<T, F>(ring: &T, a: &[Elem<T>], n: usize, divexact: F) -> Elem<T>
+where
+    T: Ring + ?Sized,
+    F: Fn(&Elem<T>, &Elem<T>) -> Option<Elem<T>>,
+{
+    if n == 0 {
+        return ring.one();
+    }
+    let mut a = a.to_vec();
+    let mut negate = false;
+    let mut prev = ring.one();
+
+    for k in 0..n - 1 {
+        if a[k*n + k].is_zero() {
+            match (k + 1..n).find(|&i| !a[i*n + k].is_zero()) {
+                Some(i) => {
+                    for j in k..n {
+                        a.swap(k*n + j, i*n + j);
+                    }
+                    negate = !negate;
+                },
+                None => return ring.zero(),
+            }
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                // a_ij = (a_ij*a_kk - a_ik*a_kj)/prev
+                let mut t = a[i*n + j].clone();
+                t.mul_assign(&a[k*n + k]);
+                let mut u = a[i*n + k].clone();
+                u.mul_assign(&a[k*n + j]);
+                t.sub_assign(&u);
+                a[i*n + j] = divexact(&t, &prev)
+                    .expect("Inexact division in Bareiss elimination.");
+            }
+        }
+        prev = a[k*n + k].clone();
+    }
+
+    let mut d = a.swap_remove(n*n - 1);
+    if negate {
+        d.neg_assign();
+    }
+    d
+}
+
+/// The determinant of the `n` by `n` matrix with row-major entries `a` by
+/// Gaussian elimination over a field.
+pub fn det_gaussian<T: DivisionRing + ?Sized>(
+    ring: &T,
+    a: &[Elem<T>],
+    n: usize
+) -> Elem<T> {
+    let mut a = a.to_vec();
+    let mut d = ring.one();
+
+    for k in 0..n {
+        let i = match (k..n).find(|&i| !a[i*n + k].is_zero()) {
+            Some(i) => i,
+            None => return ring.zero(),
+        };
+        if i != k {
+            for j in k..n {
+                a.swap(k*n + j, i*n + j);
+            }
+            d.neg_assign();
+        }
+        d.mul_assign(&a[k*n + k]);
+
+        let inv = ring.inverse(&a[k*n + k]).expect("Nonzero element is not a unit.");
+        for i in k + 1..n {
+            if a[i*n + k].is_zero() {
+                continue;
+            }
+            let mut c = a[i*n + k].clone();
+            c.mul_assign(&inv);
+            for j in k + 1..n {
+                let mut t = c.clone();
+                t.mul_assign(&a[k*n + j]);
+                a[i*n + j].sub_assign(&t);
+            }
+        }
+    }
+    d
+}
+
+impl<T: Ring> GenericMat<T> {
+    /// The determinant by Berkowitz's division free algorithm, valid over
+    /// any commutative ring, including rings with zero divisors. Panics if
+    /// the matrix is not square.
+    pub fn det_berkowitz(&self) -> Elem<T> {
+        self.check_square();
+        det_berkowitz(self.base_ring(), &self.entries, self.nrows())
+    }
+}
+
+impl<T: ExactDivisionRing> GenericMat<T> {
+    /// The determinant by fraction-free Bareiss elimination over an integral
+    /// domain. Panics if the matrix is not square.
+    pub fn det_bareiss(&self) -> Elem<T> {
+        self.check_square();
+        det_bareiss(self.base_ring(), &self.entries, self.nrows())
+    }
+}
+
+impl<T: DivisionRing> GenericMat<T> {
+    /// The determinant by Gaussian elimination over a field. Panics if the
+    /// matrix is not square.
+    pub fn det_gaussian(&self) -> Elem<T> {
+        self.check_square();
+        det_gaussian(self.base_ring(), &self.entries, self.nrows())
+    }
+}
+
+impl<T: DetRing> GenericMat<T> {
+    /// The determinant, by the algorithm the base ring prefers: Gaussian
+    /// elimination over fields, Bareiss elimination over integral domains and
+    /// Berkowitz's algorithm otherwise. Panics if the matrix is not square.
+    pub fn det(&self) -> Elem<T> {
+        self.check_square();
+        self.base_ring().det(&self.entries, self.nrows())
+    }
+}
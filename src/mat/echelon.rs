@@ -0,0 +1,177 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use crate::mat::GenericMat;
+use crate::traits::DivisionRing;
+
+// Bring the m by n matrix with row-major entries `a` to reduced row echelon
+// form over a field, with pivots taken from the first k columns, returning
+// the pivot columns.
+pub(crate) fn rref_in_place<T: DivisionRing + ?Sized>(
+    ring: &T,
+    a: &mut [Elem<T>],
+    m: usize,
+    n: usize,
+    k: usize
+) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    let mut r = 0;
+
+    for c in 0..k {
+        if r == m {
+            break;
+        }
+        let i = match (r..m).find(|&i| !a[i*n + c].is_zero()) {
+            Some(i) => i,
+            None => continue,
+        };
+        if i != r {
+            for j in c..n {
+                a.swap(i*n + j, r*n + j);
+            }
+        }
+
+        let inv = ring.inverse(&a[r*n + c]).expect("Nonzero element is not a unit.");
+        for j in c..n {
+            a[r*n + j].mul_assign(&inv);
+        }
+        for i in 0..m {
+            if i == r || a[i*n + c].is_zero() {
+                continue;
+            }
+            let f = a[i*n + c].clone();
+            for j in c..n {
+                let mut t = f.clone();
+                t.mul_assign(&a[r*n + j]);
+                a[i*n + j].sub_assign(&t);
+            }
+        }
+
+        pivots.push(c);
+        r += 1;
+    }
+    pivots
+}
+
+/// The reduced row echelon form `R = P*A` of a matrix `A` over a field.
+#[derive(Clone)]
+pub struct Rref<T: Ring> {
+    /// The reduced row echelon form `R`, with the nonzero rows first.
+    pub form: GenericMat<T>,
+    /// The column of the leading one in each nonzero row of `R`.
+    pub pivots: Vec<usize>,
+    /// The invertible square matrix `P`.
+    pub transform: GenericMat<T>,
+}
+
+impl<T: Ring> Rref<T> {
+    /// The rank, i.e. the number of pivots.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+}
+
+impl<T: DivisionRing> GenericMat<T> {
+    /// The reduced row echelon form with its pivot columns and the
+    /// transformation taking `self` to it.
+    pub fn rref(&self) -> Rref<T> {
+        let ring = self.base_ring();
+        let (m, n) = (self.nrows(), self.ncols());
+
+        // reduce [A | I]
+        let w = n + m;
+        let mut a = Vec::with_capacity(m*w);
+        for i in 0..m {
+            a.extend(self.entries[i*n..(i + 1)*n].iter().cloned());
+            a.extend((0..m).map(|j| if i == j { ring.one() } else { ring.zero() }));
+        }
+        let pivots = rref_in_place(ring, &mut a, m, w, n);
+
+        let mut form = Vec::with_capacity(m*n);
+        let mut transform = Vec::with_capacity(m*m);
+        for i in 0..m {
+            form.extend(a[i*w..i*w + n].iter().cloned());
+            transform.extend(a[i*w + n..(i + 1)*w].iter().cloned());
+        }
+
+        Rref {
+            form: GenericMat { ctx: self.ctx_with_dims(m, n), entries: form },
+            pivots,
+            transform: GenericMat { ctx: self.ctx_with_dims(m, m), entries: transform },
+        }
+    }
+
+    /// The rank.
+    pub fn rank(&self) -> usize {
+        let mut a = self.entries.clone();
+        let n = self.ncols();
+        rref_in_place(self.base_ring(), &mut a, self.nrows(), n, n).len()
+    }
+
+    /// A basis of the right kernel `{x : A*x = 0}` as the columns of a
+    /// matrix with `ncols - rank` columns, with one at a non-pivot
+    /// position and zero at the others in each column.
+    pub fn nullspace(&self) -> GenericMat<T> {
+        let ring = self.base_ring();
+        let n = self.ncols();
+        let mut a = self.entries.clone();
+        let pivots = rref_in_place(ring, &mut a, self.nrows(), n, n);
+        let free: Vec<usize> = (0..n).filter(|j| !pivots.contains(j)).collect();
+
+        let k = free.len();
+        let mut entries = vec![ring.zero(); n*k];
+        for (c, &j) in free.iter().enumerate() {
+            entries[j*k + c] = ring.one();
+            for (i, &p) in pivots.iter().enumerate() {
+                let mut t = a[i*n + j].clone();
+                t.neg_assign();
+                entries[p*k + c] = t;
+            }
+        }
+        GenericMat { ctx: self.ctx_with_dims(n, k), entries }
+    }
+
+    /// A basis of the column space as the columns of a matrix, namely the
+    /// pivot columns of `self`.
+    pub fn column_space(&self) -> GenericMat<T> {
+        let (m, n) = (self.nrows(), self.ncols());
+        let mut a = self.entries.clone();
+        let pivots = rref_in_place(self.base_ring(), &mut a, m, n, n);
+
+        let r = pivots.len();
+        let mut entries = Vec::with_capacity(m*r);
+        for i in 0..m {
+            entries.extend(pivots.iter().map(|&j| self.entries[i*n + j].clone()));
+        }
+        GenericMat { ctx: self.ctx_with_dims(m, r), entries }
+    }
+
+    /// A basis of the row space as the rows of a matrix, namely the nonzero
+    /// rows of the reduced row echelon form.
+    pub fn row_space(&self) -> GenericMat<T> {
+        let n = self.ncols();
+        let mut a = self.entries.clone();
+        let r = rref_in_place(self.base_ring(), &mut a, self.nrows(), n, n).len();
+        a.truncate(r*n);
+        GenericMat { ctx: self.ctx_with_dims(r, n), entries: a }
+    }
+}
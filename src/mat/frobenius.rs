@@ -0,0 +1,383 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::rc::Rc;
+
+use crate::mat::echelon::rref_in_place;
+use crate::mat::GenericMat;
+use crate::poly::{GenericPoly, GenericPolyRing};
+use crate::traits::DivisionRing;
+
+/// The rational canonical form `F = P^-1*A*P` of a square matrix `A`.
+#[derive(Clone)]
+pub struct FrobeniusForm<T: Ring> {
+    /// The block diagonal matrix `F` of companion matrices of the invariant
+    /// factors, each with ones on the subdiagonal and the negated
+    /// coefficients in the last column.
+    pub form: GenericMat<T>,
+    /// The invertible matrix `P`.
+    pub transform: GenericMat<T>,
+    /// The monic invariant factors `f_1 | f_2 | ... | f_r` of positive
+    /// degree, so `f_r` is the minimal polynomial and their product the
+    /// characteristic polynomial.
+    pub invariant_factors: Vec<GenericPoly<T>>,
+}
+
+// A*v for the n by n matrix A with row-major entries `a`
+fn mat_vec<T: Ring>(ring: &T, a: &[Elem<T>], n: usize, v: &[Elem<T>]) -> Vec<Elem<T>> {
+    (0..n).map(|i| {
+        let mut s = ring.zero();
+        for (j, x) in v.iter().enumerate() {
+            if x.is_zero() {
+                continue;
+            }
+            let mut t = a[i*n + j].clone();
+            t.mul_assign(x);
+            s.add_assign(&t);
+        }
+        s
+    }).collect()
+}
+
+// w*A for the row vector w
+fn vec_mat<T: Ring>(ring: &T, w: &[Elem<T>], a: &[Elem<T>], n: usize) -> Vec<Elem<T>> {
+    let mut res = vec![ring.zero(); n];
+    for (i, x) in w.iter().enumerate() {
+        if x.is_zero() {
+            continue;
+        }
+        for j in 0..n {
+            let mut t = x.clone();
+            t.mul_assign(&a[i*n + j]);
+            res[j].add_assign(&t);
+        }
+    }
+    res
+}
+
+// f(A)*v by Horner's rule
+fn poly_mat_vec<T: Ring>(
+    ring: &T,
+    f: &GenericPoly<T>,
+    a: &[Elem<T>],
+    n: usize,
+    v: &[Elem<T>]
+) -> Vec<Elem<T>> {
+    let mut res = vec![ring.zero(); n];
+    for c in f.coefficients().iter().rev() {
+        res = mat_vec(ring, a, n, &res);
+        for (x, y) in res.iter_mut().zip(v.iter()) {
+            let mut t = c.clone();
+            t.mul_assign(y);
+            x.add_assign(&t);
+        }
+    }
+    res
+}
+
+fn unit_vector<T: Ring>(ring: &T, n: usize, i: usize) -> Vec<Elem<T>> {
+    (0..n).map(|j| if i == j { ring.one() } else { ring.zero() }).collect()
+}
+
+// The Krylov basis v, A*v, ..., A^(d-1)*v of the cyclic subspace generated
+// by v, and the minimal polynomial of v, the monic f of least degree d with
+// f(A)*v = 0.
+fn krylov<T: DivisionRing>(
+    ring: &T,
+    a: &[Elem<T>],
+    n: usize,
+    v: &[Elem<T>],
+    poly_ring: &GenericPolyRing<T>
+) -> (Vec<Vec<Elem<T>>>, GenericPoly<T>) {
+    // echelonized earlier powers with pivot one, each with its expression as
+    // a combination of the powers A^i*v
+    let mut basis: Vec<(usize, Vec<Elem<T>>, Vec<Elem<T>>)> = Vec::new();
+    let mut vectors: Vec<Vec<Elem<T>>> = Vec::new();
+    let mut u = v.to_vec();
+
+    loop {
+        let k = vectors.len();
+        let mut r = u.clone();
+        let mut c = vec![ring.zero(); k + 1];
+        c[k] = ring.one();
+
+        for (p, b, bc) in basis.iter() {
+            if r[*p].is_zero() {
+                continue;
+            }
+            let f = r[*p].clone();
+            for (x, y) in r.iter_mut().zip(b.iter()) {
+                let mut t = f.clone();
+                t.mul_assign(y);
+                x.sub_assign(&t);
+            }
+            for (x, y) in c.iter_mut().zip(bc.iter()) {
+                let mut t = f.clone();
+                t.mul_assign(y);
+                x.sub_assign(&t);
+            }
+        }
+
+        match r.iter().position(|x| !x.is_zero()) {
+            None => {
+                let mut f = poly_ring.new(c);
+                f.normalize();
+                return (vectors, f);
+            },
+            Some(p) => {
+                let inv = ring.inverse(&r[p]).expect("Nonzero element is not a unit.");
+                for x in r.iter_mut().chain(c.iter_mut()) {
+                    x.mul_assign(&inv);
+                }
+                basis.push((p, r, c));
+                let w = mat_vec(ring, a, n, &u);
+                vectors.push(u);
+                u = w;
+            },
+        }
+    }
+}
+
+// The part of f coprime to g, i.e. f with every common factor removed to
+// full multiplicity.
+fn coprime_part<T: DivisionRing>(f: &GenericPoly<T>, g: &GenericPoly<T>) -> GenericPoly<T> {
+    let mut f = f.clone();
+    loop {
+        let h = f.gcd(g);
+        if h.deg().unwrap_or(0) == 0 {
+            return f;
+        }
+        f = f.divexact(&h).unwrap();
+    }
+}
+
+// A vector whose minimal polynomial is the minimal polynomial of A, with its
+// Krylov basis and minimal polynomial. Starting from e_1, each unit vector
+// whose minimal polynomial g does not divide the current f is merged: with
+// f = f'*f'' and g = g'*g'' such that f'*g' = lcm(f, g) and gcd(f', g') = 1,
+// the vector f''(A)*v + g''(A)*e_i has minimal polynomial f'*g'.
+fn maximal_vector<T: DivisionRing>(
+    ring: &T,
+    a: &[Elem<T>],
+    n: usize,
+    poly_ring: &GenericPolyRing<T>
+) -> (Vec<Vec<Elem<T>>>, GenericPoly<T>) {
+    let mut v = unit_vector(ring, n, 0);
+    let (mut vectors, mut f) = krylov(ring, a, n, &v, poly_ring);
+
+    for i in 1..n {
+        // the minimal polynomial divides the characteristic polynomial, so
+        // f is maximal once it has degree n
+        if f.deg() == Some(n) {
+            break;
+        }
+        // e_i needs no merge if its minimal polynomial divides f
+        let w = unit_vector(ring, n, i);
+        if poly_mat_vec(ring, &f, a, n, &w).iter().all(|x| x.is_zero()) {
+            continue;
+        }
+        let (_, g) = krylov(ring, a, n, &w, poly_ring);
+
+        // f' collects the primes occurring to higher power in f than in g
+        let f1 = f.divexact(&f.gcd(&g)).unwrap();
+        let fp = f.divexact(&coprime_part(&f, &f1)).unwrap();
+        let gp = coprime_part(&g, &fp);
+
+        let mut x = poly_mat_vec(ring, &f.divexact(&fp).unwrap(), a, n, &v);
+        let y = poly_mat_vec(ring, &g.divexact(&gp).unwrap(), a, n, &w);
+        for (s, t) in x.iter_mut().zip(y.iter()) {
+            s.add_assign(t);
+        }
+        v = x;
+        let (u, h) = krylov(ring, a, n, &v, poly_ring);
+        vectors = u;
+        f = h;
+    }
+    (vectors, f)
+}
+
+// The invariant factors of A in decreasing order of divisibility and the
+// columns of a transform to the block diagonal form of their companion
+// matrices, in the same order. Each step splits off the cyclic subspace W of
+// a maximal vector v with degree d minimal polynomial, together with the
+// invariant complement of vectors x with w*A^i*x = 0 for i < d, where w is
+// a functional with w*A^i*v = 0 for i < d - 1 and w*A^(d-1)*v = 1.
+fn frobenius<T: DivisionRing>(
+    ring: &T,
+    a: &[Elem<T>],
+    n: usize,
+    poly_ring: &GenericPolyRing<T>
+) -> (Vec<GenericPoly<T>>, Vec<Vec<Elem<T>>>) {
+    if n == 0 {
+        return (Vec::new(), Vec::new());
+    }
+    let (mut columns, f) = maximal_vector(ring, a, n, poly_ring);
+    let d = columns.len();
+    if d == n {
+        return (vec![f], columns);
+    }
+
+    // solve w*K = e_d for the Krylov basis K
+    let mut sys = Vec::with_capacity(d*(n + 1));
+    for (i, col) in columns.iter().enumerate() {
+        sys.extend(col.iter().cloned());
+        sys.push(if i + 1 == d { ring.one() } else { ring.zero() });
+    }
+    let pivots = rref_in_place(ring, &mut sys, d, n + 1, n);
+    let mut w = vec![ring.zero(); n];
+    for (i, &p) in pivots.iter().enumerate() {
+        w[p] = sys[i*(n + 1) + n].clone();
+    }
+
+    // the complement is the nullspace of the rows w, w*A, ..., w*A^(d-1)
+    let mut rows = Vec::with_capacity(d*n);
+    for _ in 0..d {
+        let next = vec_mat(ring, &w, a, n);
+        rows.extend(w.into_iter());
+        w = next;
+    }
+    let pivots = rref_in_place(ring, &mut rows, d, n, n);
+    let free: Vec<usize> = (0..n).filter(|j| !pivots.contains(j)).collect();
+    let complement: Vec<Vec<Elem<T>>> = free.iter().map(|&j| {
+        let mut x = unit_vector(ring, n, j);
+        for (i, &p) in pivots.iter().enumerate() {
+            let mut t = rows[i*n + j].clone();
+            t.neg_assign();
+            x[p] = t;
+        }
+        x
+    }).collect();
+
+    // the restriction of A to the complement, in the basis above, in which
+    // the coordinates of a vector are its entries in the free columns
+    let m = n - d;
+    let mut b = vec![ring.zero(); m*m];
+    for (j, x) in complement.iter().enumerate() {
+        let y = mat_vec(ring, a, n, x);
+        for (i, &k) in free.iter().enumerate() {
+            b[i*m + j] = y[k].clone();
+        }
+    }
+
+    let (mut factors, sub) = frobenius(ring, &b, m, poly_ring);
+    for s in sub.iter() {
+        let mut x = vec![ring.zero(); n];
+        for (c, u) in s.iter().zip(complement.iter()) {
+            if c.is_zero() {
+                continue;
+            }
+            for (y, z) in x.iter_mut().zip(u.iter()) {
+                let mut t = c.clone();
+                t.mul_assign(z);
+                y.add_assign(&t);
+            }
+        }
+        columns.push(x);
+    }
+    factors.insert(0, f);
+    (factors, columns)
+}
+
+impl<T: DivisionRing> GenericMat<T> {
+    /// The minimal polynomial in a new polynomial ring over the base ring in
+    /// `x`. Panics if the matrix is not square.
+    #[inline]
+    pub fn minpoly(&self) -> GenericPoly<T> {
+        self.minpoly_in(&GenericPolyRing::init(self.base_ring(), "x"))
+    }
+
+    /// The minimal polynomial in `ring`, the minimal polynomial of a vector
+    /// whose Krylov sequence spans a cyclic subspace of maximal dimension.
+    /// Panics if the matrix is not square.
+    pub fn minpoly_in(&self, ring: &GenericPolyRing<T>) -> GenericPoly<T> {
+        self.check_square();
+        if self.nrows() == 0 {
+            return ring.one();
+        }
+        maximal_vector(self.base_ring(), &self.entries, self.nrows(), ring).1
+    }
+
+    /// The Frobenius form with its invariant factors in a new polynomial
+    /// ring over the base ring in `x`. Panics if the matrix is not square.
+    #[inline]
+    pub fn frobenius_form(&self) -> FrobeniusForm<T> {
+        self.frobenius_form_in(&GenericPolyRing::init(self.base_ring(), "x"))
+    }
+
+    /// The Frobenius form with its invariant factors in `ring`. Panics if
+    /// the matrix is not square.
+    pub fn frobenius_form_in(&self, ring: &GenericPolyRing<T>) -> FrobeniusForm<T> {
+        self.check_square();
+        let base = self.base_ring();
+        let n = self.nrows();
+        let (mut factors, mut columns) = frobenius(base, &self.entries, n, ring);
+
+        // reverse the order of the blocks so the factors divide each other
+        // from left to right
+        let mut blocks = Vec::with_capacity(factors.len());
+        for f in factors.iter().rev() {
+            let d = f.deg().unwrap();
+            let k = columns.len() - d;
+            blocks.push(columns.split_off(k));
+        }
+        factors.reverse();
+        let columns: Vec<Vec<Elem<T>>> = blocks.into_iter().flatten().collect();
+
+        let mut form = vec![base.zero(); n*n];
+        let mut k = 0;
+        for f in factors.iter() {
+            let d = f.deg().unwrap();
+            for i in 1..d {
+                form[(k + i)*n + k + i - 1] = base.one();
+            }
+            for (i, c) in f.coefficients().iter().take(d).enumerate() {
+                let mut t = c.clone();
+                t.neg_assign();
+                form[(k + i)*n + k + d - 1] = t;
+            }
+            k += d;
+        }
+
+        let mut transform = vec![base.zero(); n*n];
+        for (j, col) in columns.iter().enumerate() {
+            for (i, x) in col.iter().enumerate() {
+                transform[i*n + j] = x.clone();
+            }
+        }
+
+        FrobeniusForm {
+            form: GenericMat { ctx: Rc::clone(&self.ctx), entries: form },
+            transform: GenericMat { ctx: Rc::clone(&self.ctx), entries: transform },
+            invariant_factors: factors,
+        }
+    }
+
+    /// Whether `self` and `rhs` are similar, i.e. have the same invariant
+    /// factors.
+    pub fn is_similar(&self, rhs: &Self) -> bool {
+        if !self.is_square() || !rhs.is_square() || self.nrows() != rhs.nrows() {
+            return false;
+        }
+        let ring = GenericPolyRing::init(self.base_ring(), "x");
+        let f = self.frobenius_form_in(&ring).invariant_factors;
+        let g = rhs.frobenius_form_in(&ring).invariant_factors;
+        f == g
+    }
+}
@@ -0,0 +1,442 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use crate::mat::{GenericMat, MatError};
+use crate::traits::{DivisionRing, ExactDivisionRing};
+
+// The matrix with row perm[i] equal to row i of a, i.e. P*a for the
+// permutation matrix P of perm.
+fn permute_rows<T: Ring>(a: &GenericMat<T>, perm: &[usize]) -> GenericMat<T> {
+    let n = a.ncols();
+    let mut res = a.clone();
+    for (i, &p) in perm.iter().enumerate() {
+        for j in 0..n {
+            res.entries[p*n + j] = a.entries[i*n + j].clone();
+        }
+    }
+    res
+}
+
+// The permutation matrix P of perm, with P[perm[i]][i] = 1.
+fn permutation_matrix<T: Ring>(like: &GenericMat<T>, perm: &[usize]) -> GenericMat<T> {
+    let ring = like.base_ring();
+    let m = perm.len();
+    let mut entries = vec![ring.zero(); m*m];
+    for (i, &p) in perm.iter().enumerate() {
+        entries[p*m + i] = ring.one();
+    }
+    GenericMat { ctx: like.ctx_with_dims(m, m), entries }
+}
+
+/// A rank-revealing decomposition `A = P*L*U` of an m by n matrix `A` of
+/// rank r over a field.
+#[derive(Clone)]
+pub struct Plu<T: Ring> {
+    /// The row permutation: row `i` of `L*U` is row `perm[i]` of `A`.
+    pub perm: Vec<usize>,
+    /// The m by r unit lower triangular factor `L`.
+    pub l: GenericMat<T>,
+    /// The r by n factor `U` in row echelon form.
+    pub u: GenericMat<T>,
+    /// The column of the pivot in each row of `U`.
+    pub pivots: Vec<usize>,
+}
+
+impl<T: Ring> Plu<T> {
+    /// The rank.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// The permutation matrix `P`.
+    #[inline]
+    pub fn permutation_matrix(&self) -> GenericMat<T> {
+        permutation_matrix(&self.l, &self.perm)
+    }
+
+    /// The decomposed matrix `P*L*U`.
+    pub fn reconstruct(&self) -> GenericMat<T> {
+        permute_rows(&self.l.mul_best(&self.u), &self.perm)
+    }
+}
+
+impl<T: DivisionRing> Plu<T> {
+    /// The decomposition `A = P*L*D*U` with `D` the r by r diagonal matrix
+    /// of the pivots of `U` and the returned `U` having ones at its pivots,
+    /// as the triple `(L, D, U)`.
+    pub fn ldu(&self) -> (GenericMat<T>, GenericMat<T>, GenericMat<T>) {
+        let ring = self.l.base_ring();
+        let (r, n) = (self.rank(), self.u.ncols());
+        let mut d = vec![ring.zero(); r*r];
+        let mut u = self.u.clone();
+        for (i, &p) in self.pivots.iter().enumerate() {
+            let inv = ring.inverse(&self.u.entries[i*n + p])
+                .expect("Nonzero element is not a unit.");
+            for x in u.entries[i*n + p..(i + 1)*n].iter_mut() {
+                x.mul_assign(&inv);
+            }
+            d[i*r + i] = self.u.entries[i*n + p].clone();
+        }
+        (self.l.clone(), GenericMat { ctx: self.u.ctx_with_dims(r, r), entries: d }, u)
+    }
+
+    /// A solution of `A*X = b` with zero at the non-pivot positions, or an
+    /// error if the numbers of rows differ or the system is inconsistent.
+    /// Each column of `b` is a right-hand side.
+    pub fn solve(&self, b: &GenericMat<T>) -> Result<GenericMat<T>, MatError> {
+        let ring = self.l.base_ring();
+        let (m, r, n, k) = (self.l.nrows(), self.rank(), self.u.ncols(), b.ncols());
+        if b.nrows() != m {
+            return Err(MatError::DimensionMismatch {
+                lhs: (m, n),
+                rhs: (b.nrows(), k),
+            });
+        }
+
+        // forward substitution L*Y = P^-1*b, with the last m - r rows of L
+        // giving the consistency conditions
+        let mut y: Vec<Elem<T>> = Vec::with_capacity(m*k);
+        for &p in self.perm.iter() {
+            y.extend(b.entries[p*k..(p + 1)*k].iter().cloned());
+        }
+        for i in 1..m {
+            for j in 0..std::cmp::min(i, r) {
+                let f = &self.l.entries[i*r + j];
+                if f.is_zero() {
+                    continue;
+                }
+                for c in 0..k {
+                    let mut t = f.clone();
+                    t.mul_assign(&y[j*k + c]);
+                    y[i*k + c].sub_assign(&t);
+                }
+            }
+        }
+        if y[r*k..].iter().any(|x| !x.is_zero()) {
+            return Err(MatError::Inconsistent);
+        }
+
+        // back substitution U*X = Y
+        let mut x = vec![ring.zero(); n*k];
+        for (i, &p) in self.pivots.iter().enumerate().rev() {
+            let inv = ring.inverse(&self.u.entries[i*n + p])
+                .expect("Nonzero element is not a unit.");
+            for c in 0..k {
+                let mut s = y[i*k + c].clone();
+                for j in p + 1..n {
+                    let mut t = self.u.entries[i*n + j].clone();
+                    t.mul_assign(&x[j*k + c]);
+                    s.sub_assign(&t);
+                }
+                s.mul_assign(&inv);
+                x[p*k + c] = s;
+            }
+        }
+        Ok(GenericMat { ctx: self.u.ctx_with_dims(n, k), entries: x })
+    }
+}
+
+/// A fraction-free decomposition `A = P*L*D^-1*U` of an m by n matrix `A`
+/// of rank r over an integral domain, by Bareiss elimination. With the
+/// successive pivots `p_1, ..., p_r`, the diagonal entries of `L` and `U`,
+/// `D` is the diagonal matrix of the `p_(k-1)*p_k`, where `p_0 = 1`.
+#[derive(Clone)]
+pub struct FractionFreeLu<T: Ring> {
+    /// The row permutation: row `i` of `L*D^-1*U` is row `perm[i]` of `A`.
+    pub perm: Vec<usize>,
+    /// The m by r lower triangular factor `L`.
+    pub l: GenericMat<T>,
+    /// The diagonal of `D`.
+    pub d: Vec<Elem<T>>,
+    /// The r by n factor `U` in row echelon form.
+    pub u: GenericMat<T>,
+    /// The column of the pivot in each row of `U`.
+    pub pivots: Vec<usize>,
+}
+
+impl<T: Ring> FractionFreeLu<T> {
+    /// The rank.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// The permutation matrix `P`.
+    #[inline]
+    pub fn permutation_matrix(&self) -> GenericMat<T> {
+        permutation_matrix(&self.l, &self.perm)
+    }
+
+    /// The r by r diagonal matrix `D`.
+    pub fn diagonal_matrix(&self) -> GenericMat<T> {
+        let ring = self.l.base_ring();
+        let r = self.rank();
+        let mut entries = vec![ring.zero(); r*r];
+        for (i, x) in self.d.iter().enumerate() {
+            entries[i*r + i] = x.clone();
+        }
+        GenericMat { ctx: self.l.ctx_with_dims(r, r), entries }
+    }
+}
+
+impl<T: ExactDivisionRing> FractionFreeLu<T> {
+    /// A solution `(X, den)` of `A*X = den*b` with `den` the last pivot
+    /// `p_r`, the leading minor of `A` in the pivot columns, and `X` zero
+    /// at the non-pivot positions, or an error if the numbers of rows
+    /// differ or the system is inconsistent. Each column of `b` is a
+    /// right-hand side.
+    pub fn solve(&self, b: &GenericMat<T>) -> Result<(GenericMat<T>, Elem<T>), MatError> {
+        let ring = self.l.base_ring();
+        let (m, r, n, k) = (self.l.nrows(), self.rank(), self.u.ncols(), b.ncols());
+        if b.nrows() != m {
+            return Err(MatError::DimensionMismatch {
+                lhs: (m, n),
+                rhs: (b.nrows(), k),
+            });
+        }
+
+        // forward substitution by the Bareiss steps on the columns of
+        // P^-1*b, with the last m - r rows giving the consistency conditions
+        let mut y: Vec<Elem<T>> = Vec::with_capacity(m*k);
+        for &p in self.perm.iter() {
+            y.extend(b.entries[p*k..(p + 1)*k].iter().cloned());
+        }
+        let mut prev = ring.one();
+        for j in 0..r {
+            let pj = &self.l.entries[j*r + j];
+            for i in j + 1..m {
+                let f = &self.l.entries[i*r + j];
+                for c in 0..k {
+                    // y_i = (p_j*y_i - l_ij*y_j)/p_(j-1)
+                    let mut t = y[i*k + c].clone();
+                    t.mul_assign(pj);
+                    let mut s = f.clone();
+                    s.mul_assign(&y[j*k + c]);
+                    t.sub_assign(&s);
+                    y[i*k + c] = ring.divexact(&t, &prev)
+                        .expect("Inexact division in fraction-free substitution.");
+                }
+            }
+            prev = pj.clone();
+        }
+        if y[r*k..].iter().any(|x| !x.is_zero()) {
+            return Err(MatError::Inconsistent);
+        }
+
+        // back substitution U*X = den*Y, exact as den*X is given by Cramer's
+        // rule on the pivot columns
+        let den = prev;
+        let mut x = vec![ring.zero(); n*k];
+        for (i, &p) in self.pivots.iter().enumerate().rev() {
+            for c in 0..k {
+                let mut s = y[i*k + c].clone();
+                s.mul_assign(&den);
+                for j in p + 1..n {
+                    let mut t = self.u.entries[i*n + j].clone();
+                    t.mul_assign(&x[j*k + c]);
+                    s.sub_assign(&t);
+                }
+                x[p*k + c] = ring.divexact(&s, &self.u.entries[i*n + p])
+                    .expect("Inexact division in fraction-free substitution.");
+            }
+        }
+        Ok((GenericMat { ctx: self.u.ctx_with_dims(n, k), entries: x }, den))
+    }
+
+    /// The decomposed matrix `P*L*D^-1*U`. The partial sums
+    /// `A_(k-1) = (L_k*U_k + p_(k-1)*A_k)/p_k` over the columns `L_k` of `L`
+    /// and rows `U_k` of `U` are the intermediate matrices of the elimination,
+    /// so every division is exact.
+    pub fn reconstruct(&self) -> GenericMat<T> {
+        let ring = self.l.base_ring();
+        let (m, r, n) = (self.l.nrows(), self.rank(), self.u.ncols());
+
+        let mut a = vec![ring.zero(); m*n];
+        for k in (0..r).rev() {
+            let pk = &self.l.entries[k*r + k];
+            let prev = if k == 0 {
+                ring.one()
+            } else {
+                self.l.entries[(k - 1)*r + k - 1].clone()
+            };
+            for i in k..m {
+                for j in 0..n {
+                    let mut t = self.l.entries[i*r + k].clone();
+                    t.mul_assign(&self.u.entries[k*n + j]);
+                    let mut s = prev.clone();
+                    s.mul_assign(&a[i*n + j]);
+                    t.add_assign(&s);
+                    a[i*n + j] = ring.divexact(&t, pk)
+                        .expect("Inexact division in fraction-free reconstruction.");
+                }
+            }
+        }
+
+        let a = GenericMat { ctx: self.u.ctx_with_dims(m, n), entries: a };
+        permute_rows(&a, &self.perm)
+    }
+}
+
+impl<T: DivisionRing> GenericMat<T> {
+    /// The rank-revealing decomposition `A = P*L*U` by Gaussian elimination
+    /// with row pivoting, for any dimensions and rank.
+    pub fn plu(&self) -> Plu<T> {
+        let ring = self.base_ring();
+        let (m, n) = (self.nrows(), self.ncols());
+        let mut a = self.entries.clone();
+        let mut perm: Vec<usize> = (0..m).collect();
+        // multipliers, column k below row k, stored m by m
+        let mut l = vec![ring.zero(); m*m];
+        let mut pivots = Vec::new();
+        let mut r = 0;
+
+        for c in 0..n {
+            if r == m {
+                break;
+            }
+            let i = match (r..m).find(|&i| !a[i*n + c].is_zero()) {
+                Some(i) => i,
+                None => continue,
+            };
+            if i != r {
+                for j in c..n {
+                    a.swap(i*n + j, r*n + j);
+                }
+                for j in 0..r {
+                    l.swap(i*m + j, r*m + j);
+                }
+                perm.swap(i, r);
+            }
+
+            let inv = ring.inverse(&a[r*n + c]).expect("Nonzero element is not a unit.");
+            for i in r + 1..m {
+                if a[i*n + c].is_zero() {
+                    continue;
+                }
+                let mut f = a[i*n + c].clone();
+                f.mul_assign(&inv);
+                a[i*n + c] = ring.zero();
+                for j in c + 1..n {
+                    let mut t = f.clone();
+                    t.mul_assign(&a[r*n + j]);
+                    a[i*n + j].sub_assign(&t);
+                }
+                l[i*m + r] = f;
+            }
+
+            pivots.push(c);
+            r += 1;
+        }
+
+        let mut lr = Vec::with_capacity(m*r);
+        for i in 0..m {
+            lr.extend(l[i*m..i*m + r].iter().cloned());
+            if i < r {
+                lr[i*r + i] = ring.one();
+            }
+        }
+        a.truncate(r*n);
+
+        Plu {
+            perm,
+            l: GenericMat { ctx: self.ctx_with_dims(m, r), entries: lr },
+            u: GenericMat { ctx: self.ctx_with_dims(r, n), entries: a },
+            pivots,
+        }
+    }
+}
+
+impl<T: ExactDivisionRing> GenericMat<T> {
+    /// The fraction-free decomposition `A = P*L*D^-1*U` by Bareiss
+    /// elimination with row pivoting, for any dimensions and rank. All
+    /// entries stay in the base ring.
+    pub fn fraction_free_lu(&self) -> FractionFreeLu<T> {
+        let ring = self.base_ring();
+        let (m, n) = (self.nrows(), self.ncols());
+        let mut a = self.entries.clone();
+        let mut perm: Vec<usize> = (0..m).collect();
+        // pivot columns of the intermediate matrices, stored m by m
+        let mut l = vec![ring.zero(); m*m];
+        let mut pivots = Vec::new();
+        let mut d = Vec::new();
+        let mut prev = ring.one();
+        let mut r = 0;
+
+        for c in 0..n {
+            if r == m {
+                break;
+            }
+            let i = match (r..m).find(|&i| !a[i*n + c].is_zero()) {
+                Some(i) => i,
+                None => continue,
+            };
+            if i != r {
+                for j in c..n {
+                    a.swap(i*n + j, r*n + j);
+                }
+                for j in 0..r {
+                    l.swap(i*m + j, r*m + j);
+                }
+                perm.swap(i, r);
+            }
+
+            let p = a[r*n + c].clone();
+            for i in r..m {
+                l[i*m + r] = a[i*n + c].clone();
+            }
+            for i in r + 1..m {
+                for j in c + 1..n {
+                    // a_ij = (p*a_ij - a_ic*a_rj)/prev
+                    let mut t = a[i*n + j].clone();
+                    t.mul_assign(&p);
+                    let mut u = a[i*n + c].clone();
+                    u.mul_assign(&a[r*n + j]);
+                    t.sub_assign(&u);
+                    a[i*n + j] = ring.divexact(&t, &prev)
+                        .expect("Inexact division in Bareiss elimination.");
+                }
+                a[i*n + c] = ring.zero();
+            }
+
+            let mut dk = prev;
+            dk.mul_assign(&p);
+            d.push(dk);
+            prev = p;
+            pivots.push(c);
+            r += 1;
+        }
+
+        let mut lr = Vec::with_capacity(m*r);
+        for i in 0..m {
+            lr.extend(l[i*m..i*m + r].iter().cloned());
+        }
+        a.truncate(r*n);
+
+        FractionFreeLu {
+            perm,
+            l: GenericMat { ctx: self.ctx_with_dims(m, r), entries: lr },
+            d,
+            u: GenericMat { ctx: self.ctx_with_dims(r, n), entries: a },
+            pivots,
+        }
+    }
+}
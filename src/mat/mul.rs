@@ -0,0 +1,251 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::cmp::min;
+use std::rc::Rc;
+
+use crate::mat::{GenericMat, GenericMatCtx, MatError};
+
+// c += a*b for row-major a (m by n), b (n by p) and c (m by p), looping over
+// blocks of size bs so the working set stays in cache
+#[allow(clippy::too_many_arguments)]
+fn mul_blocked_into<T: Ring>(
+    a: &[Elem<T>],
+    b: &[Elem<T>],
+    c: &mut [Elem<T>],
+    m: usize,
+    n: usize,
+    p: usize,
+    bs: usize
+) {
+    for i0 in (0..m).step_by(bs) {
+        for k0 in (0..n).step_by(bs) {
+            for j0 in (0..p).step_by(bs) {
+                for i in i0..min(i0 + bs, m) {
+                    for k in k0..min(k0 + bs, n) {
+                        let x = &a[i*n + k];
+                        if x.is_zero() {
+                            continue;
+                        }
+                        for j in j0..min(j0 + bs, p) {
+                            let mut t = x.clone();
+                            t.mul_assign(&b[k*p + j]);
+                            c[i*p + j].add_assign(&t);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// a dense row-major submatrix, padded with zeros
+struct Block<T: Ring> {
+    rows: usize,
+    cols: usize,
+    data: Vec<Elem<T>>,
+}
+
+impl<T: Ring> Block<T> {
+    // the rows by cols block at (r0, c0), with zeros outside of self
+    fn block(&self, ring: &T, r0: usize, c0: usize, rows: usize, cols: usize) -> Self {
+        let mut data = Vec::with_capacity(rows*cols);
+        for i in r0..r0 + rows {
+            for j in c0..c0 + cols {
+                if i < self.rows && j < self.cols {
+                    data.push(self.data[i*self.cols + j].clone());
+                } else {
+                    data.push(ring.zero());
+                }
+            }
+        }
+        Block { rows, cols, data }
+    }
+
+    fn plus(&self, rhs: &Self) -> Self {
+        let mut data = self.data.clone();
+        for (x, y) in data.iter_mut().zip(rhs.data.iter()) {
+            x.add_assign(y);
+        }
+        Block { rows: self.rows, cols: self.cols, data }
+    }
+
+    fn minus(&self, rhs: &Self) -> Self {
+        let mut data = self.data.clone();
+        for (x, y) in data.iter_mut().zip(rhs.data.iter()) {
+            x.sub_assign(y);
+        }
+        Block { rows: self.rows, cols: self.cols, data }
+    }
+
+    // copy the part of src that fits into self at (r0, c0)
+    fn insert(&mut self, src: &Self, r0: usize, c0: usize) {
+        for i in 0..min(src.rows, self.rows.saturating_sub(r0)) {
+            for j in 0..min(src.cols, self.cols.saturating_sub(c0)) {
+                self.data[(r0 + i)*self.cols + c0 + j] = src.data[i*src.cols + j].clone();
+            }
+        }
+    }
+}
+
+// Strassen-Winograd recursion, padding odd dimensions with zeros. It uses
+// seven half-size products and fifteen additions and does not assume the
+// base ring is commutative.
+fn strassen<T: Ring>(
+    ring: &T,
+    a: &Block<T>,
+    b: &Block<T>,
+    threshold: usize,
+    bs: usize
+) -> Block<T> {
+    let (m, n, p) = (a.rows, a.cols, b.cols);
+    if min(m, min(n, p)) <= threshold {
+        let mut data = vec![ring.zero(); m*p];
+        mul_blocked_into::<T>(&a.data, &b.data, &mut data, m, n, p, bs);
+        return Block { rows: m, cols: p, data };
+    }
+
+    let (m2, n2, p2) = ((m + 1)/2, (n + 1)/2, (p + 1)/2);
+    let a11 = a.block(ring, 0, 0, m2, n2);
+    let a12 = a.block(ring, 0, n2, m2, n2);
+    let a21 = a.block(ring, m2, 0, m2, n2);
+    let a22 = a.block(ring, m2, n2, m2, n2);
+    let b11 = b.block(ring, 0, 0, n2, p2);
+    let b12 = b.block(ring, 0, p2, n2, p2);
+    let b21 = b.block(ring, n2, 0, n2, p2);
+    let b22 = b.block(ring, n2, p2, n2, p2);
+
+    let s1 = a21.plus(&a22);
+    let s2 = s1.minus(&a11);
+    let s3 = a11.minus(&a21);
+    let s4 = a12.minus(&s2);
+    let t1 = b12.minus(&b11);
+    let t2 = b22.minus(&t1);
+    let t3 = b22.minus(&b12);
+    let t4 = t2.minus(&b21);
+
+    let m1 = strassen(ring, &a11, &b11, threshold, bs);
+    let m2_ = strassen(ring, &a12, &b21, threshold, bs);
+    let m3 = strassen(ring, &s4, &b22, threshold, bs);
+    let m4 = strassen(ring, &a22, &t4, threshold, bs);
+    let m5 = strassen(ring, &s1, &t1, threshold, bs);
+    let m6 = strassen(ring, &s2, &t2, threshold, bs);
+    let m7 = strassen(ring, &s3, &t3, threshold, bs);
+
+    let c11 = m1.plus(&m2_);
+    let u2 = m1.plus(&m6);
+    let u3 = u2.plus(&m7);
+    let u4 = u2.plus(&m5);
+    let c12 = u4.plus(&m3);
+    let c21 = u3.minus(&m4);
+    let c22 = u3.plus(&m5);
+
+    let mut c = Block { rows: m, cols: p, data: vec![ring.zero(); m*p] };
+    c.insert(&c11, 0, 0);
+    c.insert(&c12, 0, p2);
+    c.insert(&c21, m2, 0);
+    c.insert(&c22, m2, p2);
+    c
+}
+
+impl<T: Ring> GenericMat<T> {
+    // the space of the products self*rhs, reusing the space of an operand
+    // when it has the right shape
+    fn product_ctx(&self, rhs: &Self) -> Rc<GenericMatCtx<T>> {
+        let (m, p) = (self.nrows(), rhs.ncols());
+        if self.ncols() == p {
+            Rc::clone(&self.ctx)
+        } else if rhs.nrows() == m {
+            Rc::clone(&rhs.ctx)
+        } else {
+            self.ctx_with_dims(m, p)
+        }
+    }
+
+    /// The product by the classical triple loop. The dimensions must be
+    /// compatible.
+    #[inline]
+    pub fn mul_classical(&self, rhs: &Self) -> Self {
+        let bs = std::cmp::max(self.nrows(), std::cmp::max(self.ncols(), rhs.ncols()));
+        self.mul_blocked(rhs, std::cmp::max(bs, 1))
+    }
+
+    /// The product by the triple loop over blocks of size `bs`. The
+    /// dimensions must be compatible.
+    pub fn mul_blocked(&self, rhs: &Self, bs: usize) -> Self {
+        let (m, n, p) = (self.nrows(), self.ncols(), rhs.ncols());
+        let mut entries = vec![self.base_ring().zero(); m*p];
+        mul_blocked_into::<T>(&self.entries, &rhs.entries, &mut entries, m, n, p, bs);
+        GenericMat {
+            ctx: self.product_ctx(rhs),
+            entries,
+        }
+    }
+
+    /// The product by Strassen-Winograd recursion down to dimension
+    /// `threshold`, below which blocks of size `bs` are multiplied directly.
+    /// The dimensions must be compatible.
+    pub fn mul_strassen(&self, rhs: &Self, threshold: usize, bs: usize) -> Self {
+        let ring = self.base_ring();
+        let a = Block::<T> {
+            rows: self.nrows(),
+            cols: self.ncols(),
+            data: self.entries.clone(),
+        };
+        let b = Block::<T> {
+            rows: rhs.nrows(),
+            cols: rhs.ncols(),
+            data: rhs.entries.clone(),
+        };
+        let c = strassen(ring, &a, &b, std::cmp::max(threshold, 1), bs);
+        GenericMat {
+            ctx: self.product_ctx(rhs),
+            entries: c.data,
+        }
+    }
+
+    /// The product, using Strassen-Winograd recursion when all dimensions
+    /// exceed the threshold of the space of `self` and the cache-blocked
+    /// kernel otherwise. The dimensions must be compatible.
+    pub fn mul_best(&self, rhs: &Self) -> Self {
+        let bs = self.ctx.block_size.get();
+        let threshold = self.ctx.strassen_threshold.get();
+        let d = min(self.nrows(), min(self.ncols(), rhs.ncols()));
+        if d > threshold {
+            self.mul_strassen(rhs, threshold, bs)
+        } else {
+            self.mul_blocked(rhs, bs)
+        }
+    }
+
+    /// The product `self*rhs`, or an error if the number of columns of
+    /// `self` is not the number of rows of `rhs`. The product belongs to the
+    /// space of an operand of the same shape if there is one, otherwise to a
+    /// new space.
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, MatError> {
+        if self.ncols() != rhs.nrows() {
+            return Err(MatError::DimensionMismatch {
+                lhs: (self.nrows(), self.ncols()),
+                rhs: (rhs.nrows(), rhs.ncols()),
+            });
+        }
+        Ok(self.mul_best(rhs))
+    }
+}
@@ -19,13 +19,28 @@
 use inertia_algebra::structures::*;
 use inertia_algebra::ops::*;
 
-use crate::mat::GenericMat;
+use crate::mat::{GenericMat, MatError};
+
+impl<T: Ring> GenericMat<T> {
+    // panic unless self and rhs have the same dimensions
+    #[inline]
+    fn check_same_dims(&self, rhs: &Self) {
+        let lhs = (self.ctx.nrows as usize, self.ctx.ncols as usize);
+        let rhs = (rhs.ctx.nrows as usize, rhs.ctx.ncols as usize);
+        if lhs != rhs {
+            let e = MatError::DimensionMismatch { lhs, rhs };
+            panic!("{}", e);
+        }
+    }
+}
 
 // Neg
 impl<T: Ring> NegAssign for GenericMat<T> {
     #[inline]
     fn neg_assign(&mut self) {
-        unimplemented!()
+        for x in self.entries.iter_mut() {
+            x.neg_assign();
+        }
     }
 }
 
@@ -49,14 +64,20 @@ impl<T: Ring> Neg for &GenericMat<T> {
 
 // Add
 impl<T: Ring> AddAssign<&GenericMat<T>> for GenericMat<T> {
-    fn add_assign(&mut self, _rhs: &GenericMat<T>) {
-        unimplemented!()
+    fn add_assign(&mut self, rhs: &GenericMat<T>) {
+        self.check_same_dims(rhs);
+        for (x, y) in self.entries.iter_mut().zip(rhs.entries.iter()) {
+            x.add_assign(y);
+        }
     }
 }
 
 impl<T: Ring> AddFrom<&GenericMat<T>> for GenericMat<T> {
-    fn add_from(&mut self, _lhs: &GenericMat<T>) {
-        unimplemented!()
+    fn add_from(&mut self, lhs: &GenericMat<T>) {
+        self.check_same_dims(lhs);
+        for (x, y) in self.entries.iter_mut().zip(lhs.entries.iter()) {
+            x.add_from(y);
+        }
     }
 }
 
@@ -70,14 +91,20 @@ forward_binop! {
 // Sub
 
 impl<T: Ring> SubAssign<&Self> for GenericMat<T> {
-    fn sub_assign(&mut self, _rhs: &Self) {
-        unimplemented!()
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.check_same_dims(rhs);
+        for (x, y) in self.entries.iter_mut().zip(rhs.entries.iter()) {
+            x.sub_assign(y);
+        }
     }
 }
 
 impl<T: Ring> SubFrom<&Self> for GenericMat<T> {
-    fn sub_from(&mut self, _lhs: &Self) {
-        unimplemented!()
+    fn sub_from(&mut self, lhs: &Self) {
+        self.check_same_dims(lhs);
+        for (x, y) in self.entries.iter_mut().zip(lhs.entries.iter()) {
+            x.sub_from(y);
+        }
     }
 }
 
@@ -88,18 +115,21 @@ forward_binop! {
     SubFrom, sub_from
 }
 
-/* TODO: op guard to check matrix dim
 // Mul
 
+/// Replaces `self` by the product `self*rhs`, which changes its shape unless
+/// `rhs` is square. Panics if the dimensions are incompatible.
 impl<T: Ring> MulAssign<&Self> for GenericMat<T> {
-    fn mul_assign(&mut self, _rhs: &Self) {
-        unimplemented!()
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = self.try_mul(rhs).unwrap_or_else(|e| panic!("{}", e));
     }
 }
 
+/// Replaces `self` by the product `lhs*self`. Panics if the dimensions are
+/// incompatible.
 impl<T: Ring> MulFrom<&Self> for GenericMat<T> {
-    fn mul_from(&mut self, _lhs: &Self) {
-        unimplemented!()
+    fn mul_from(&mut self, lhs: &Self) {
+        *self = lhs.try_mul(self).unwrap_or_else(|e| panic!("{}", e));
     }
 }
 
@@ -109,4 +139,3 @@ forward_binop! {
     MulAssign, mul_assign
     MulFrom, mul_from
 }
-*/
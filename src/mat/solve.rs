@@ -0,0 +1,173 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use crate::mat::echelon::rref_in_place;
+use crate::mat::{berkowitz, GenericMat, MatError};
+use crate::traits::{DivisionRing, InvertibleRing};
+
+/// The solutions of a linear system: every solution is `particular` plus a
+/// combination of the columns of `kernel` for `A*X = B`, or of the rows of
+/// `kernel` for `X*A = B`, in each column or row respectively.
+#[derive(Clone)]
+pub struct Solution<T: Ring> {
+    /// One solution `X`.
+    pub particular: GenericMat<T>,
+    /// A basis of the kernel of `A` on the side of the unknown.
+    pub kernel: GenericMat<T>,
+}
+
+impl<T: DivisionRing> GenericMat<T> {
+    /// The solutions of `self*X = b`, or an error if the numbers of rows
+    /// differ or the system is inconsistent. Each column of `b` is a
+    /// right-hand side.
+    pub fn solve(&self, b: &Self) -> Result<Solution<T>, MatError> {
+        let ring = self.base_ring();
+        let (m, n, k) = (self.nrows(), self.ncols(), b.ncols());
+        if b.nrows() != m {
+            return Err(MatError::DimensionMismatch {
+                lhs: (m, n),
+                rhs: (b.nrows(), k),
+            });
+        }
+
+        // reduce [A | B] with pivots in A
+        let w = n + k;
+        let mut a = Vec::with_capacity(m*w);
+        for i in 0..m {
+            a.extend(self.entries[i*n..(i + 1)*n].iter().cloned());
+            a.extend(b.entries[i*k..(i + 1)*k].iter().cloned());
+        }
+        let pivots = rref_in_place(ring, &mut a, m, w, n);
+        let r = pivots.len();
+        if a[r*w..].iter().any(|x| !x.is_zero()) {
+            return Err(MatError::Inconsistent);
+        }
+
+        let mut particular = vec![ring.zero(); n*k];
+        for (i, &p) in pivots.iter().enumerate() {
+            for c in 0..k {
+                particular[p*k + c] = a[i*w + n + c].clone();
+            }
+        }
+
+        let free: Vec<usize> = (0..n).filter(|j| !pivots.contains(j)).collect();
+        let d = free.len();
+        let mut kernel = vec![ring.zero(); n*d];
+        for (c, &j) in free.iter().enumerate() {
+            kernel[j*d + c] = ring.one();
+            for (i, &p) in pivots.iter().enumerate() {
+                let mut t = a[i*w + j].clone();
+                t.neg_assign();
+                kernel[p*d + c] = t;
+            }
+        }
+
+        Ok(Solution {
+            particular: GenericMat { ctx: self.ctx_with_dims(n, k), entries: particular },
+            kernel: GenericMat { ctx: self.ctx_with_dims(n, d), entries: kernel },
+        })
+    }
+
+    /// The solutions of `X*self = b`, or an error if the numbers of columns
+    /// differ or the system is inconsistent. Each row of `b` is a right-hand
+    /// side.
+    pub fn solve_left(&self, b: &Self) -> Result<Solution<T>, MatError> {
+        if b.ncols() != self.ncols() {
+            return Err(MatError::DimensionMismatch {
+                lhs: (self.nrows(), self.ncols()),
+                rhs: (b.nrows(), b.ncols()),
+            });
+        }
+        let s = self.transpose().solve(&b.transpose())?;
+        Ok(Solution {
+            particular: s.particular.transpose(),
+            kernel: s.kernel.transpose(),
+        })
+    }
+
+    /// The inverse, or an error if the matrix is not square or singular.
+    pub fn inverse(&self) -> Result<Self, MatError> {
+        if !self.is_square() {
+            return Err(MatError::NotSquare {
+                nrows: self.nrows(),
+                ncols: self.ncols(),
+            });
+        }
+        let rref = self.rref();
+        if rref.rank() < self.nrows() {
+            return Err(MatError::Singular);
+        }
+        Ok(rref.transform)
+    }
+}
+
+impl<T: Ring> GenericMat<T> {
+    // the adjugate and determinant by the Cayley-Hamilton theorem: with
+    // charpoly x^n + c_(n-1)*x^(n-1) + ... + c_0 we have
+    // adj(A) = (-1)^(n+1)*(A^(n-1) + c_(n-1)*A^(n-2) + ... + c_1*I)
+    // and det(A) = (-1)^n*c_0, without any divisions
+    fn adjugate_det(&self) -> (Self, Elem<T>) {
+        let ring = self.base_ring();
+        let n = self.nrows();
+        let c = berkowitz(ring, &self.entries, n);
+
+        let mut adj = self.parent().one();
+        for k in (1..n).rev() {
+            adj = self.mul_best(&adj);
+            for i in 0..n {
+                adj.entries[i*n + i].add_assign(&c[k]);
+            }
+        }
+        let mut det = c[0].clone();
+        if n % 2 == 1 {
+            det.neg_assign();
+        } else {
+            adj.neg_assign();
+        }
+        (adj, det)
+    }
+
+    /// The adjugate `adj(A)` with `A*adj(A) = det(A)*I`, valid over any
+    /// commutative ring. Panics if the matrix is not square.
+    pub fn adjugate(&self) -> Self {
+        self.check_square();
+        self.adjugate_det().0
+    }
+}
+
+impl<T: InvertibleRing> GenericMat<T> {
+    /// The inverse `adj(A)/det(A)` over a commutative ring, or an error if
+    /// the matrix is not square or its determinant is not a unit.
+    pub fn inverse_adjugate(&self) -> Result<Self, MatError> {
+        if !self.is_square() {
+            return Err(MatError::NotSquare {
+                nrows: self.nrows(),
+                ncols: self.ncols(),
+            });
+        }
+        let (mut adj, det) = self.adjugate_det();
+        let inv = self.base_ring().inverse(&det).ok_or(MatError::Singular)?;
+        for x in adj.entries.iter_mut() {
+            x.mul_assign(&inv);
+        }
+        Ok(adj)
+    }
+}
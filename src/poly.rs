@@ -23,9 +23,14 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
+use crate::mat::{bareiss_with, det_berkowitz};
+use crate::traits::{DetRing, DivisionRing, ExactDivisionRing, GcdRing, InvertibleRing};
+use crate::util::{check_unital, ring_integer};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+mod factor;
 mod ops;
 
 ///////////////////////////////////////////////////////////////////
@@ -182,8 +187,11 @@ impl<T: Ring> Distributive for GenericPolyRing<T> {}
 impl<T: Ring> PolynomialRing<T> for GenericPolyRing<T> {
     type Element = GenericPoly<T>;
 
+    /// Panics if the base ring has no identity, e.g. a non-square matrix
+    /// space.
     #[inline]
     fn init<S: Into<String>>(base_ring: &T, var: S) -> Self {
+        check_unital(base_ring);
         GenericPolyRing {
             ctx: Rc::new(GenericPolyCtx::new(base_ring.clone(), var))
         }
@@ -330,8 +338,11 @@ impl<T: Ring> Element for GenericPoly<T> {
 }
 
 impl<T: Ring> Operation<Additive> for GenericPoly<T> {
-    fn operate(&self, _right: &Self) -> Self {
-        unimplemented!()
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        let mut res = self.clone();
+        res.add_assign(right);
+        res
     }
 }
 
@@ -342,14 +353,18 @@ impl<T: Ring> IsIdentity<Additive> for GenericPoly<T> {
 }
 
 impl<T: Ring> TwoSidedInverse<Additive> for GenericPoly<T> {
+    #[inline]
     fn two_sided_inverse(&self) -> Self {
-        unimplemented!()
+        -self
     }
 }
 
 impl<T: Ring> Operation<Multiplicative> for GenericPoly<T> {
-    fn operate(&self, _right: &Self) -> Self {
-        unimplemented!()
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        let mut res = self.clone();
+        res.mul_assign(right);
+        res
     }
 }
 
@@ -413,21 +428,11 @@ impl<T: Ring> GenericPoly<T>
 where
     //T: PartialEq,
 {
-    /*
-    // TODO: Dont resize? Check what flint does.
-    // remove trailing zeros and ensure len >= 1
-    fn normalize(&mut self) {
-        let len = self.len();
-        if len != 1 {
-            let z = self.base_ring().default();
-            let coeffs = self.coefficients_mut();
-            if let Some(pos) = coeffs.iter().rev().position(|x| x != &z) {
-                coeffs.truncate(len - pos);
-            } else {
-                coeffs.clear();
-            }
-        }
-    }*/
+    /// Remove trailing zero coefficients, keeping at least one coefficient.
+    pub fn normalize(&mut self) {
+        let len = self.coeffs.iter().rposition(|c| !c.is_zero()).map_or(1, |i| i + 1);
+        self.coeffs.truncate(len);
+    }
 
     #[inline]
     pub fn resize(&mut self, new_len: usize) {
@@ -499,52 +504,394 @@ where
         self.into_coefficients()
     }
 
-    /*
-    // TODO: use addmul/muladd? Better choice of val/ref for inputs? assign?
-    pub fn mul_classical<'a>(&'a self, rhs: &'a GenericPoly<T>) -> Self
-    where
-        &'a T: Mul<Output = T>,
-        T: AddAssign,
-    {
-        let mut res = self.parent().zero();
-        res.resize(self.len() + rhs.len() - 1);
+    /// The degree, ignoring trailing zero coefficients. Returns `None` for
+    /// the zero polynomial.
+    #[inline]
+    pub(crate) fn deg(&self) -> Option<usize> {
+        self.coeffs.iter().rposition(|c| !c.is_zero())
+    }
 
-        for (i, a) in self.coefficients().iter().enumerate() {
-            for (j, b) in rhs.coefficients().iter().enumerate() {
-                (*res.coeff_mut(i + j)).add_assign(a.mul(b));
-            }
+    /// The leading coefficient, ignoring trailing zero coefficients.
+    #[inline]
+    pub fn leading_coefficient(&self) -> &Elem<T> {
+        &self.coeffs[self.deg().unwrap_or(0)]
+    }
+
+    /// The number of nonzero coefficients.
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.coeffs.iter().filter(|c| !c.is_zero()).count()
+    }
+
+    /// Iterate over the nonzero terms as `(exponent, coefficient)` pairs, in
+    /// increasing order of exponent.
+    #[inline]
+    pub fn terms(&self) -> impl Iterator<Item = (usize, &Elem<T>)> {
+        self.coeffs.iter().enumerate().filter(|(_, c)| !c.is_zero())
+    }
+
+    /// The x-adic valuation, i.e. the index of the lowest nonzero coefficient.
+    /// Returns `None` for the zero polynomial.
+    #[inline]
+    pub fn valuation(&self) -> Option<usize> {
+        self.coeffs.iter().position(|c| !c.is_zero())
+    }
+
+    /// The lowest nonzero term as an `(exponent, coefficient)` pair. Returns
+    /// `None` for the zero polynomial.
+    #[inline]
+    pub fn lowest_term(&self) -> Option<(usize, &Elem<T>)> {
+        self.terms().next()
+    }
+
+    /// The reversal `x^n * f(1/x)`. Coefficients of degree greater than `n`
+    /// are discarded, so this is the reversal of `f mod x^(n+1)`.
+    pub fn reverse(&self, n: usize) -> Self {
+        let zero = self.base_ring().zero();
+        let mut coeffs = Vec::with_capacity(n + 1);
+        for i in (0..=n).rev() {
+            coeffs.push(self.coeffs.get(i).unwrap_or(&zero).clone());
+        }
+        let mut res = GenericPoly {
+            coeffs,
+            ctx: Rc::clone(&self.ctx),
+        };
+        res.normalize();
+        res
+    }
+
+    /// Multiply by `x^k`.
+    pub fn shift_left(&self, k: usize) -> Self {
+        let mut res = self.clone();
+        if self.valuation().is_some() {
+            let zero = self.base_ring().zero();
+            res.coeffs.splice(0..0, std::iter::repeat(zero).take(k));
+        }
+        res
+    }
+
+    /// Divide by `x^k`, discarding the terms of degree less than `k`.
+    pub fn shift_right(&self, k: usize) -> Self {
+        let mut res = self.clone();
+        res.coeffs.drain(0..std::cmp::min(k, self.len()));
+        if res.coeffs.is_empty() {
+            res.coeffs.push(self.base_ring().zero());
         }
         res
     }
-   
-    pub fn mul_assign_classical<'a>(&mut self, rhs: &'a GenericPoly<T>)
-    where
-        T: for<'b> MulAssign<&'b T>,
-        T: AddAssign<&'a T>,
-    {
-        let temp = self.clone();
-        self.resize(self.len() + rhs.len() - 1);
 
-        for (i, a) in temp.coefficients().iter().enumerate() {
-            for (j, b) in rhs.coefficients().iter().enumerate() {
-                (*self.coeff_mut(i + j)).add_assign(a.mul(b));
+    /// Evaluate at `a` using Horner's method.
+    pub fn evaluate(&self, a: &Elem<T>) -> Elem<T> {
+        let mut res = self.base_ring().zero();
+        for c in self.coeffs.iter().rev() {
+            res.mul_assign(a);
+            res.add_assign(c);
+        }
+        res
+    }
+
+    /// Multiply every coefficient by the scalar `c`.
+    pub fn scalar_mul_assign(&mut self, c: &Elem<T>) {
+        for a in self.coeffs.iter_mut() {
+            a.mul_assign(c);
+        }
+        self.normalize();
+    }
+
+    #[inline]
+    pub fn scalar_mul(&self, c: &Elem<T>) -> Self {
+        let mut res = self.clone();
+        res.scalar_mul_assign(c);
+        res
+    }
+
+    /// The formal derivative.
+    pub fn derivative(&self) -> Self {
+        let ring = self.base_ring();
+        let mut coeffs: Vec<Elem<T>> = self.coeffs.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| {
+                let mut t = ring_integer(ring, i as u64);
+                t.mul_assign(c);
+                t
+            })
+            .collect();
+        if coeffs.is_empty() {
+            coeffs.push(ring.zero());
+        }
+
+        let mut res = GenericPoly {
+            coeffs,
+            ctx: Rc::clone(&self.ctx),
+        };
+        res.normalize();
+        res
+    }
+
+    /// Classical (schoolbook) multiplication, keeping only the terms of
+    /// degree less than `n`.
+    pub fn mullow_classical(&self, rhs: &GenericPoly<T>, n: usize) -> Self {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return self.parent().zero();
+        }
+        let len = std::cmp::min(self.len() + rhs.len() - 1, n);
+        let mut coeffs = vec![self.base_ring().zero(); std::cmp::max(len, 1)];
+
+        for (i, a) in self.coeffs.iter().enumerate().take(len) {
+            if a.is_zero() {
+                continue;
+            }
+            for (j, b) in rhs.coeffs.iter().enumerate().take(len - i) {
+                let mut t = a.clone();
+                t.mul_assign(b);
+                coeffs[i + j].add_assign(&t);
             }
         }
+
+        let mut res = GenericPoly {
+            coeffs,
+            ctx: Rc::clone(&self.ctx),
+        };
+        res.normalize();
+        res
+    }
+
+    /// Classical (schoolbook) multiplication.
+    #[inline]
+    pub fn mul_classical(&self, rhs: &GenericPoly<T>) -> Self {
+        self.mullow_classical(rhs, (self.len() + rhs.len()).saturating_sub(1))
     }
-    */
 
     //pub fn mul_karatsuba(&self, rhs: &GenericPoly<T>) -> Self {
     //}
 
+    /// Multiply, keeping only the terms of degree less than `n`.
+    #[inline]
+    pub fn mullow(&self, rhs: &GenericPoly<T>, n: usize) -> Self {
+        self.mullow_classical(rhs, n)
+    }
+
     /// Make a best guess for an optimal multiplication algorithm.
-    pub fn mul_assign_best(&mut self, _rhs: &GenericPoly<T>) {
-        unimplemented!()
+    #[inline]
+    pub fn mul_assign_best(&mut self, rhs: &GenericPoly<T>) {
+        *self = self.mul_classical(rhs);
     }
     
-    // TODO: assumes commutative!
     /// Make a best guess for an optimal multiplication algorithm.
     #[inline]
     pub fn mul_from_best(&mut self, lhs: &GenericPoly<T>) {
-        self.mul_assign_best(lhs);
+        *self = lhs.mul_classical(self);
+    }
+}
+
+impl<T: InvertibleRing> GenericPoly<T> {
+    /// Division with remainder. Returns `None` if the leading coefficient of
+    /// `rhs` is not a unit.
+    pub fn divrem(&self, rhs: &GenericPoly<T>) -> Option<(Self, Self)> {
+        let db = rhs.deg()?;
+        let inv = self.base_ring().inverse(&rhs.coeffs[db])?;
+
+        let mut r = self.clone();
+        r.normalize();
+        let len = r.len().saturating_sub(db);
+        let mut q = vec![self.base_ring().zero(); std::cmp::max(len, 1)];
+
+        while let Some(dr) = r.deg() {
+            if dr < db {
+                break;
+            }
+            let k = dr - db;
+            let mut c = r.coeffs[dr].clone();
+            c.mul_assign(&inv);
+            for (j, b) in rhs.coeffs.iter().enumerate().take(db) {
+                let mut t = c.clone();
+                t.mul_assign(b);
+                r.coeffs[k + j].sub_assign(&t);
+            }
+            r.coeffs[dr] = self.base_ring().zero();
+            r.normalize();
+            q[k] = c;
+        }
+
+        let mut q = GenericPoly {
+            coeffs: q,
+            ctx: Rc::clone(&self.ctx),
+        };
+        q.normalize();
+        Some((q, r))
+    }
+
+    /// Divide by the leading coefficient. Returns `None` if it is not a
+    /// unit.
+    pub fn make_monic(&self) -> Option<Self> {
+        let inv = self.base_ring().inverse(self.leading_coefficient())?;
+        Some(self.scalar_mul(&inv))
+    }
+}
+
+impl<T: DivisionRing> GenericPoly<T> {
+    /// The monic greatest common divisor, or zero if both inputs are zero.
+    pub fn gcd(&self, rhs: &GenericPoly<T>) -> Self {
+        let mut a = self.clone();
+        let mut b = rhs.clone();
+        while b.deg().is_some() {
+            let (_, r) = a.divrem(&b).unwrap();
+            a = b;
+            b = r;
+        }
+        a.make_monic().unwrap_or(a)
+    }
+
+    /// The extended gcd `(g, s, t)` with `g = s*self + t*rhs` the monic
+    /// greatest common divisor, or zero if both inputs are zero.
+    pub fn xgcd(&self, rhs: &GenericPoly<T>) -> (Self, Self, Self) {
+        let parent = self.parent();
+        let (mut r0, mut r1) = (self.clone(), rhs.clone());
+        let (mut s0, mut s1) = (parent.one(), parent.zero());
+        let (mut t0, mut t1) = (parent.zero(), parent.one());
+
+        while r1.deg().is_some() {
+            let (q, r) = r0.divrem(&r1).unwrap();
+            let s = &s0 - &q * &s1;
+            let t = &t0 - &q * &t1;
+            r0 = std::mem::replace(&mut r1, r);
+            s0 = std::mem::replace(&mut s1, s);
+            t0 = std::mem::replace(&mut t1, t);
+        }
+
+        match r0.deg() {
+            Some(d) => {
+                let inv = self.base_ring().inverse(&r0.coeffs[d]).unwrap();
+                (r0.scalar_mul(&inv), s0.scalar_mul(&inv), t0.scalar_mul(&inv))
+            },
+            None => (r0, s0, t0),
+        }
+    }
+
+    /// The square-free factorization `[(a_1, 1), (a_2, 2), ...]` of the monic
+    /// part of `self` by Yun's algorithm, omitting trivial factors. Only
+    /// valid in characteristic zero or characteristic greater than the
+    /// degree.
+    pub fn squarefree_factorization(&self) -> Vec<(Self, usize)> {
+        let mut res = Vec::new();
+        let f = match self.make_monic() {
+            Some(f) if f.deg().unwrap_or(0) > 0 => f,
+            _ => return res,
+        };
+
+        let df = f.derivative();
+        let a = f.gcd(&df);
+        let mut b = f.divexact(&a).unwrap();
+        let c = df.divexact(&a).unwrap();
+        let mut d = &c - &b.derivative();
+        let mut i = 1;
+
+        while b.deg().unwrap_or(0) > 0 {
+            let a = b.gcd(&d);
+            b = b.divexact(&a).unwrap();
+            let c = d.divexact(&a).unwrap();
+            d = &c - &b.derivative();
+            if a.deg().unwrap_or(0) > 0 {
+                res.push((a, i));
+            }
+            i += 1;
+        }
+        res
+    }
+}
+
+impl<T: Ring> GenericPoly<T> {
+    // the exact quotient self/rhs, using divexact for the coefficients
+    fn divexact_by<F>(&self, rhs: &GenericPoly<T>, divexact: F) -> Option<Self>
+    where
+        F: Fn(&Elem<T>, &Elem<T>) -> Option<Elem<T>>,
+    {
+        let ring = self.base_ring();
+        let db = rhs.deg()?;
+        let lead = &rhs.coeffs[db];
+
+        let mut r = self.clone();
+        r.normalize();
+        let len = r.len().saturating_sub(db);
+        let mut q = vec![ring.zero(); std::cmp::max(len, 1)];
+
+        while let Some(dr) = r.deg() {
+            if dr < db {
+                return None;
+            }
+            let k = dr - db;
+            let c = divexact(&r.coeffs[dr], lead)?;
+            for (j, b) in rhs.coeffs.iter().enumerate().take(db) {
+                let mut t = c.clone();
+                t.mul_assign(b);
+                r.coeffs[k + j].sub_assign(&t);
+            }
+            r.coeffs[dr] = ring.zero();
+            r.normalize();
+            q[k] = c;
+        }
+
+        let mut q = GenericPoly {
+            coeffs: q,
+            ctx: Rc::clone(&self.ctx),
+        };
+        q.normalize();
+        Some(q)
+    }
+}
+
+impl<T: ExactDivisionRing> GenericPoly<T> {
+    /// The exact quotient `self/rhs`, or `None` if `rhs` does not divide
+    /// `self`.
+    #[inline]
+    pub fn divexact(&self, rhs: &GenericPoly<T>) -> Option<Self> {
+        let ring = self.base_ring();
+        self.divexact_by(rhs, |x, y| ring.divexact(x, y))
+    }
+}
+
+impl<T: ExactDivisionRing> ExactDivisionRing for GenericPolyRing<T> {
+    #[inline]
+    fn divexact(&self, a: &GenericPoly<T>, b: &GenericPoly<T>) -> Option<GenericPoly<T>> {
+        a.divexact(b)
+    }
+}
+
+/// Fraction-free Bareiss elimination over integral domains and Berkowitz's
+/// algorithm otherwise, e.g. over `Zmod` with a composite modulus.
+impl<T: DetRing> DetRing for GenericPolyRing<T> {
+    fn det(&self, a: &[GenericPoly<T>], n: usize) -> GenericPoly<T> {
+        if self.is_integral_domain() {
+            bareiss_with(self, a, n, |x, y| self.domain_divexact(x, y))
+        } else {
+            det_berkowitz(self, a, n)
+        }
+    }
+
+    #[inline]
+    fn is_integral_domain(&self) -> bool {
+        self.base_ring().is_integral_domain()
+    }
+
+    #[inline]
+    fn domain_divexact(&self, a: &GenericPoly<T>, b: &GenericPoly<T>) -> Option<GenericPoly<T>> {
+        let ring = self.base_ring();
+        a.divexact_by(b, |x, y| ring.domain_divexact(x, y))
+    }
+}
+
+impl<T: DivisionRing> GcdRing for GenericPolyRing<T> {
+    #[inline]
+    fn gcd(&self, a: &GenericPoly<T>, b: &GenericPoly<T>) -> GenericPoly<T> {
+        a.gcd(b)
+    }
+
+    #[inline]
+    fn canonical_unit(&self, a: &GenericPoly<T>) -> GenericPoly<T> {
+        match a.deg() {
+            Some(d) => self.new(vec![a.coeffs[d].clone()]),
+            None => self.one(),
+        }
     }
 }
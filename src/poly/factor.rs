@@ -0,0 +1,233 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use crate::finite_field::field_order;
+use crate::poly::GenericPoly;
+use crate::traits::FiniteField;
+
+impl<T: FiniteField> GenericPoly<T> {
+    // self^e mod m for m of positive degree
+    fn powmod(&self, e: u128, m: &Self) -> Self {
+        let (_, a) = self.divrem(m).unwrap();
+        let mut res = self.parent().one();
+        for i in (0..128 - e.leading_zeros()).rev() {
+            res = (&res * &res).divrem(m).unwrap().1;
+            if (e >> i) & 1 == 1 {
+                res = (&res * &a).divrem(m).unwrap().1;
+            }
+        }
+        res
+    }
+
+    // the polynomial x in the same ring
+    fn var_poly(&self) -> Self {
+        let mut x = self.parent().zero();
+        x.set_coefficient(1, self.base_ring().one());
+        x
+    }
+
+    // the p-th root of a polynomial in x^p, taking p-th roots of the
+    // coefficients by the inverse of the Frobenius map
+    fn pth_root(&self) -> Self {
+        let ring = self.base_ring();
+        let p = ring.characteristic() as usize;
+        let k = ring.degree() - 1;
+        let coeffs: Vec<Elem<T>> = self.coeffs.iter()
+            .step_by(p)
+            .map(|c| (0..k).fold(c.clone(), |a, _| ring.frobenius(&a)))
+            .collect();
+        let mut res = self.parent().new(coeffs);
+        res.normalize();
+        res
+    }
+
+    /// Whether the polynomial is irreducible over the finite base field, by
+    /// Ben-Or's test.
+    pub fn is_irreducible(&self) -> bool {
+        let f = match self.make_monic() {
+            Some(f) => f,
+            None => return false,
+        };
+        let n = match f.deg() {
+            Some(n) if n > 0 => n,
+            _ => return false,
+        };
+        let q = field_order(self.base_ring());
+        let x = f.var_poly();
+
+        // gcd(x^(q^i) - x, f) = 1 for all i <= n/2
+        let mut h = x.clone();
+        for _ in 0..n/2 {
+            h = h.powmod(q, &f);
+            if (&h - &x).gcd(&f).deg() != Some(0) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// The square-free factorization `[(a_1, 1), (a_2, 2), ...]` of the monic
+    /// part over the finite base field, omitting trivial factors.
+    pub fn factor_squarefree(&self) -> Vec<(Self, usize)> {
+        let mut res = Vec::new();
+        let f = match self.make_monic() {
+            Some(f) if f.deg().unwrap_or(0) > 0 => f,
+            _ => return res,
+        };
+        let p = self.base_ring().characteristic() as usize;
+
+        let df = f.derivative();
+        let mut c = if df.deg().is_none() {
+            f
+        } else {
+            let mut c = f.gcd(&df);
+            let mut w = f.divexact(&c).unwrap();
+            let mut i = 1;
+            while w.deg().unwrap_or(0) > 0 {
+                let y = w.gcd(&c);
+                let z = w.divexact(&y).unwrap();
+                if z.deg().unwrap_or(0) > 0 {
+                    res.push((z, i));
+                }
+                i += 1;
+                c = c.divexact(&y).unwrap();
+                w = y;
+            }
+            c
+        };
+
+        // what remains is a polynomial in x^p
+        if c.deg().unwrap_or(0) > 0 {
+            c = c.pth_root();
+            res.extend(c.factor_squarefree().into_iter().map(|(g, m)| (g, m*p)));
+        }
+        res
+    }
+
+    /// The distinct-degree factorization `[(g_1, d_1), ...]` of the monic
+    /// part of a square-free polynomial, where `g_i` is the product of its
+    /// irreducible factors of degree `d_i`.
+    pub fn factor_distinct_degree(&self) -> Vec<(Self, usize)> {
+        let mut res = Vec::new();
+        let mut f = match self.make_monic() {
+            Some(f) if f.deg().unwrap_or(0) > 0 => f,
+            _ => return res,
+        };
+        let q = field_order(self.base_ring());
+        let x = f.var_poly();
+
+        let mut h = x.divrem(&f).unwrap().1;
+        let mut i = 1;
+        while f.deg().unwrap_or(0) >= 2*i {
+            h = h.powmod(q, &f);
+            let g = (&h - &x).gcd(&f);
+            if g.deg() != Some(0) {
+                f = f.divexact(&g).unwrap();
+                h = h.divrem(&f).unwrap().1;
+                res.push((g, i));
+            }
+            i += 1;
+        }
+        if let Some(d) = f.deg().filter(|&d| d > 0) {
+            res.push((f, d));
+        }
+        res
+    }
+
+    /// The irreducible factors of the monic part of a square-free polynomial
+    /// whose irreducible factors all have degree `d`, by the Cantor-Zassenhaus
+    /// algorithm with a fixed pseudorandom sequence.
+    pub fn factor_equal_degree(&self, d: usize) -> Vec<Self> {
+        let f = match self.make_monic() {
+            Some(f) if f.deg().unwrap_or(0) > 0 => f,
+            _ => return Vec::new(),
+        };
+        let n = f.deg().unwrap();
+        if n <= d {
+            return vec![f];
+        }
+
+        let ring = self.base_ring();
+        let q = field_order(ring);
+        let one = self.parent().one();
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+
+        loop {
+            let coeffs: Vec<Elem<T>> = (0..n).map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                ring.element(state as u128 % q)
+            }).collect();
+            let mut a = self.parent().new(coeffs);
+            a.normalize();
+            if a.deg().unwrap_or(0) == 0 {
+                continue;
+            }
+
+            let mut g = a.gcd(&f);
+            if g.deg() == Some(0) {
+                let b = if ring.characteristic() == 2 {
+                    // the trace a + a^2 + ... + a^(2^(kd - 1)) for q = 2^k
+                    let k = ring.degree() as usize * d;
+                    let mut t = a.clone();
+                    let mut s = a.clone();
+                    for _ in 1..k {
+                        t = t.powmod(2, &f);
+                        s.add_assign(&t);
+                    }
+                    s
+                } else {
+                    // a^((q^d - 1)/2) = (a^(1 + q + ... + q^(d-1)))^((q - 1)/2)
+                    let mut t = a.clone();
+                    let mut s = a.clone();
+                    for _ in 1..d {
+                        t = t.powmod(q, &f);
+                        s = (&s * &t).divrem(&f).unwrap().1;
+                    }
+                    let mut s = s.powmod((q - 1)/2, &f);
+                    s.sub_assign(&one);
+                    s
+                };
+                g = b.gcd(&f);
+            }
+
+            if g.deg().map_or(false, |k| k > 0 && k < n) {
+                let h = f.divexact(&g).unwrap();
+                let mut res = g.factor_equal_degree(d);
+                res.extend(h.factor_equal_degree(d));
+                return res;
+            }
+        }
+    }
+
+    /// The factorization of the monic part into irreducible factors with
+    /// multiplicities over the finite base field.
+    pub fn factor(&self) -> Vec<(Self, usize)> {
+        let mut res = Vec::new();
+        for (g, m) in self.factor_squarefree() {
+            for (h, d) in g.factor_distinct_degree() {
+                res.extend(h.factor_equal_degree(d).into_iter().map(|u| (u, m)));
+            }
+        }
+        res
+    }
+}
@@ -58,6 +58,7 @@ impl<T: Ring> AddAssign<&GenericPoly<T>> for GenericPoly<T> {
         for (i, c) in rhs.coefficients().iter().enumerate() {
             self.coeff_mut(i).add_assign(c);
         }
+        self.normalize();
     }
 }
 
@@ -69,6 +70,7 @@ impl<T: Ring> AddFrom<&GenericPoly<T>> for GenericPoly<T> {
         for (i, c) in lhs.coefficients().iter().enumerate() {
             self.coeff_mut(i).add_from(c);
         }
+        self.normalize();
     }
 }
 
@@ -89,17 +91,20 @@ impl<T: Ring> SubAssign<&Self> for GenericPoly<T> {
         for (i, c) in rhs.coefficients().iter().enumerate() {
             self.coeff_mut(i).sub_assign(c);
         }
+        self.normalize();
     }
 }
 
 impl<T: Ring> SubFrom<&Self> for GenericPoly<T> {
-    fn sub_from(&mut self, rhs: &Self) {
-        let max = std::cmp::max(self.len(), rhs.len());
+    fn sub_from(&mut self, lhs: &Self) {
+        let max = std::cmp::max(self.len(), lhs.len());
         self.resize(max);
 
-        for (i, c) in rhs.coefficients().iter().enumerate() {
-            self.coeff_mut(i).sub_from(c);
+        let zero = self.base_ring().zero();
+        for (i, c) in self.coeffs_mut().iter_mut().enumerate() {
+            c.sub_from(lhs.coefficients().get(i).unwrap_or(&zero));
         }
+        self.normalize();
     }
 }
 
@@ -113,16 +118,16 @@ forward_binop! {
 // Mul
 
 impl<T: Ring> MulAssign<&Self> for GenericPoly<T> {
-    fn mul_assign(&mut self, _rhs: &Self) {
-        //self.mul_assign_best(rhs);
-        unimplemented!()
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign_best(rhs);
     }
 }
 
 impl<T: Ring> MulFrom<&Self> for GenericPoly<T> {
-    fn mul_from(&mut self, _lhs: &Self) {
-        //self.mul_from_best(lhs);
-        unimplemented!()
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        self.mul_from_best(lhs);
     }
 }
 
@@ -0,0 +1,514 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use std::cell::OnceCell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::mat::{charpoly_hessenberg, det_gaussian};
+use crate::traits::{DetRing, DivisionRing, ExactDivisionRing, FiniteField, GcdRing, InvertibleRing};
+use crate::util::{factor_u128, is_prime_u64};
+
+mod ops;
+
+///////////////////////////////////////////////////////////////////
+// PrimeField
+///////////////////////////////////////////////////////////////////
+
+// Residues are kept in Montgomery form a*R mod p with R = 2^64, except for
+// p = 2 where they are stored directly.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct PrimeFieldCtx {
+    pub(crate) p: u64,
+    // -p^-1 mod R
+    pub(crate) pinv: u64,
+    // R^2 mod p
+    pub(crate) r2: u64,
+    // R mod p, the Montgomery form of one
+    pub(crate) one: u64,
+    // the prime factors of p - 1, computed on first use
+    #[cfg_attr(feature = "serde", serde(skip))]
+    factors: OnceCell<Vec<u64>>,
+    // the Montgomery form of the least primitive root, computed on first use
+    #[cfg_attr(feature = "serde", serde(skip))]
+    primitive_root: OnceCell<u64>,
+}
+
+impl PrimeFieldCtx {
+    pub fn new(p: u64) -> Self {
+        if p == 2 {
+            return PrimeFieldCtx {
+                p,
+                pinv: 0,
+                r2: 1,
+                one: 1,
+                factors: OnceCell::new(),
+                primitive_root: OnceCell::new(),
+            };
+        }
+
+        // Newton iteration for p^-1 mod R, doubling the correct bits from
+        // the three given by p*p = 1 mod 8
+        let mut inv = p;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inv)));
+        }
+        let r = ((1u128 << 64) % p as u128) as u64;
+        let r2 = (r as u128 * r as u128 % p as u128) as u64;
+        PrimeFieldCtx {
+            p,
+            pinv: inv.wrapping_neg(),
+            r2,
+            one: r,
+            factors: OnceCell::new(),
+            primitive_root: OnceCell::new(),
+        }
+    }
+
+    // the distinct prime factors of p - 1
+    pub(crate) fn factors(&self) -> &[u64] {
+        self.factors.get_or_init(|| {
+            factor_u128((self.p - 1).into()).into_iter().map(|(r, _)| r as u64).collect()
+        })
+    }
+
+    // the Montgomery form of the least generator of the multiplicative group
+    pub(crate) fn primitive_root(&self) -> u64 {
+        *self.primitive_root.get_or_init(|| {
+            let p = self.p;
+            (1..p).map(|g| self.to_mont(g))
+                .find(|&g| self.factors().iter().all(|r| self.pow(g, (p - 1)/r) != self.one))
+                .unwrap()
+        })
+    }
+
+    // Montgomery reduction t*R^-1 mod p for t < p*R
+    #[inline]
+    fn redc(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.pinv);
+        let (s, carry) = t.overflowing_add(m as u128 * self.p as u128);
+        let mut u = (s >> 64) | ((carry as u128) << 64);
+        if u >= self.p as u128 {
+            u -= self.p as u128;
+        }
+        u as u64
+    }
+
+    #[inline]
+    pub(crate) fn mul(&self, a: u64, b: u64) -> u64 {
+        if self.p == 2 {
+            a & b
+        } else {
+            self.redc(a as u128 * b as u128)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add(&self, a: u64, b: u64) -> u64 {
+        let (s, carry) = a.overflowing_add(b);
+        if carry || s >= self.p {
+            s.wrapping_sub(self.p)
+        } else {
+            s
+        }
+    }
+
+    #[inline]
+    pub(crate) fn sub(&self, a: u64, b: u64) -> u64 {
+        if a >= b {
+            a - b
+        } else {
+            a.wrapping_sub(b).wrapping_add(self.p)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn to_mont(&self, x: u64) -> u64 {
+        self.mul(x % self.p, self.r2)
+    }
+
+    #[inline]
+    pub(crate) fn from_mont(&self, a: u64) -> u64 {
+        if self.p == 2 {
+            a
+        } else {
+            self.redc(a as u128)
+        }
+    }
+
+    pub(crate) fn pow(&self, a: u64, e: u64) -> u64 {
+        let mut res = self.one;
+        for i in (0..64 - e.leading_zeros()).rev() {
+            res = self.mul(res, res);
+            if (e >> i) & 1 == 1 {
+                res = self.mul(res, a);
+            }
+        }
+        res
+    }
+}
+
+/// The prime field `GF(p)` for a prime `p < 2^64`, using Montgomery
+/// multiplication with constants precomputed in the context.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PrimeField {
+    pub(crate) ctx: Rc<PrimeFieldCtx>,
+}
+
+impl PrimeField {
+    /// The field with `p` elements. Panics if `p` is not prime.
+    #[inline]
+    pub fn init(p: u64) -> Self {
+        assert!(is_prime_u64(p), "Modulus must be prime.");
+        PrimeField {
+            ctx: Rc::new(PrimeFieldCtx::new(p))
+        }
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.ctx.p
+    }
+
+    // the element with Montgomery form a
+    #[inline]
+    pub(crate) fn from_raw(&self, a: u64) -> PrimeFieldElem {
+        PrimeFieldElem {
+            ctx: Rc::clone(&self.ctx),
+            val: a,
+        }
+    }
+
+    /// The residue class of `x`.
+    #[inline]
+    pub fn from_u64(&self, x: u64) -> PrimeFieldElem {
+        self.from_raw(self.ctx.to_mont(x))
+    }
+
+    /// The residue class of `x`.
+    #[inline]
+    pub fn from_i128(&self, x: i128) -> PrimeFieldElem {
+        self.from_u64(x.rem_euclid(self.ctx.p as i128) as u64)
+    }
+
+    /// The least generator of the multiplicative group, cached in the
+    /// context after the first call.
+    #[inline]
+    pub fn primitive_root(&self) -> PrimeFieldElem {
+        self.from_raw(self.ctx.primitive_root())
+    }
+
+    /// A primitive `n`-th root of unity, which exists if and only if `n`
+    /// divides `p - 1`.
+    pub fn root_of_unity(&self, n: u64) -> Option<PrimeFieldElem> {
+        let p = self.ctx.p;
+        if n == 0 || (p - 1) % n != 0 {
+            return None;
+        }
+        let g = self.ctx.primitive_root();
+        Some(self.from_raw(self.ctx.pow(g, (p - 1)/n)))
+    }
+}
+
+impl NewElement<&PrimeFieldElem> for PrimeField {
+    #[inline]
+    fn new(&self, val: &PrimeFieldElem) -> PrimeFieldElem {
+        val.clone()
+    }
+}
+
+macro_rules! impl_new_element {
+    ($($t:ident)*) => ($(
+        impl NewElement<$t> for PrimeField {
+            #[inline]
+            fn new(&self, val: $t) -> PrimeFieldElem {
+                self.from_i128(val as i128)
+            }
+        }
+
+        impl NewElement<&$t> for PrimeField {
+            #[inline]
+            fn new(&self, val: &$t) -> PrimeFieldElem {
+                self.from_i128(*val as i128)
+            }
+        }
+    )*)
+}
+
+impl_new_element! { usize u8 u16 u32 u64 isize i8 i16 i32 i64 }
+
+impl fmt::Display for PrimeField {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Finite field of order {}", self.modulus())
+    }
+}
+
+impl Eq for PrimeField {}
+
+impl PartialEq for PrimeField {
+    #[inline]
+    fn eq(&self, rhs: &PrimeField) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) || self.modulus() == rhs.modulus()
+    }
+}
+
+impl Hash for PrimeField {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.modulus().hash(state);
+    }
+}
+
+impl Parent for PrimeField {
+    type Element = PrimeFieldElem;
+}
+
+impl Identity<Additive> for PrimeField {
+    #[inline]
+    fn identity(&self) -> PrimeFieldElem {
+        self.from_raw(0)
+    }
+}
+
+impl Divisible<Additive> for PrimeField {}
+
+impl Associative<Additive> for PrimeField {}
+
+impl Commutative<Additive> for PrimeField {}
+
+impl Identity<Multiplicative> for PrimeField {
+    #[inline]
+    fn identity(&self) -> PrimeFieldElem {
+        self.from_raw(self.ctx.one)
+    }
+}
+
+impl Divisible<Multiplicative> for PrimeField {}
+
+impl Associative<Multiplicative> for PrimeField {}
+
+impl Commutative<Multiplicative> for PrimeField {}
+
+impl Distributive for PrimeField {}
+
+impl InvertibleRing for PrimeField {
+    #[inline]
+    fn inverse(&self, a: &PrimeFieldElem) -> Option<PrimeFieldElem> {
+        a.inv()
+    }
+
+    #[inline]
+    fn is_unit(&self, a: &PrimeFieldElem) -> bool {
+        a.val != 0
+    }
+}
+
+impl ExactDivisionRing for PrimeField {
+    #[inline]
+    fn divexact(&self, a: &PrimeFieldElem, b: &PrimeFieldElem) -> Option<PrimeFieldElem> {
+        Some(a * &b.inv()?)
+    }
+}
+
+impl DivisionRing for PrimeField {}
+
+impl DetRing for PrimeField {
+    #[inline]
+    fn det(&self, a: &[PrimeFieldElem], n: usize) -> PrimeFieldElem {
+        det_gaussian(self, a, n)
+    }
+
+    #[inline]
+    fn charpoly(&self, a: &[PrimeFieldElem], n: usize) -> Vec<PrimeFieldElem> {
+        charpoly_hessenberg(self, a, n)
+    }
+}
+
+impl GcdRing for PrimeField {
+    #[inline]
+    fn gcd(&self, a: &PrimeFieldElem, b: &PrimeFieldElem) -> PrimeFieldElem {
+        if a.val == 0 && b.val == 0 {
+            self.from_raw(0)
+        } else {
+            self.from_raw(self.ctx.one)
+        }
+    }
+
+    #[inline]
+    fn canonical_unit(&self, a: &PrimeFieldElem) -> PrimeFieldElem {
+        if a.val == 0 {
+            self.from_raw(self.ctx.one)
+        } else {
+            a.clone()
+        }
+    }
+}
+
+impl FiniteField for PrimeField {
+    #[inline]
+    fn characteristic(&self) -> u64 {
+        self.ctx.p
+    }
+
+    #[inline]
+    fn degree(&self) -> u32 {
+        1
+    }
+
+    /// The residue class of `i`.
+    #[inline]
+    fn element(&self, i: u128) -> PrimeFieldElem {
+        self.from_u64((i % self.ctx.p as u128) as u64)
+    }
+
+    #[inline]
+    fn frobenius(&self, a: &PrimeFieldElem) -> PrimeFieldElem {
+        a.clone()
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// PrimeFieldElem
+///////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PrimeFieldElem {
+    pub(crate) ctx: Rc<PrimeFieldCtx>,
+    pub(crate) val: u64,
+}
+
+impl fmt::Debug for PrimeFieldElem {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} mod {}", self.value(), self.ctx.p)
+    }
+}
+
+impl fmt::Display for PrimeFieldElem {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl Eq for PrimeFieldElem {}
+
+impl PartialEq for PrimeFieldElem {
+    #[inline]
+    fn eq(&self, rhs: &PrimeFieldElem) -> bool {
+        self.val == rhs.val
+    }
+}
+
+impl Hash for PrimeFieldElem {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent().hash(state);
+        self.val.hash(state);
+    }
+}
+
+impl Element for PrimeFieldElem {
+    type Parent = PrimeField;
+
+    #[inline]
+    fn parent(&self) -> PrimeField {
+        PrimeField {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl Operation<Additive> for PrimeFieldElem {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl IsIdentity<Additive> for PrimeFieldElem {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.val == 0
+    }
+}
+
+impl TwoSidedInverse<Additive> for PrimeFieldElem {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl Operation<Multiplicative> for PrimeFieldElem {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl IsIdentity<Multiplicative> for PrimeFieldElem {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.val == self.ctx.one
+    }
+}
+
+/// Panics on zero.
+impl TwoSidedInverse<Multiplicative> for PrimeFieldElem {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inv().expect("Division by zero.")
+    }
+}
+
+impl PrimeFieldElem {
+    /// The residue in `[0, p)`.
+    #[inline]
+    pub fn value(&self) -> u64 {
+        self.ctx.from_mont(self.val)
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.ctx.p
+    }
+
+    /// Raise to the power `e` by repeated squaring.
+    #[inline]
+    pub fn pow(&self, e: u64) -> Self {
+        self.parent().from_raw(self.ctx.pow(self.val, e))
+    }
+
+    /// The multiplicative inverse `a^(p-2)`, or `None` for zero.
+    #[inline]
+    pub fn inv(&self) -> Option<Self> {
+        if self.val == 0 {
+            None
+        } else {
+            Some(self.pow(self.ctx.p - 2))
+        }
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::prime_field::PrimeFieldElem;
+
+// Neg
+impl NegAssign for PrimeFieldElem {
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.val = self.ctx.sub(0, self.val);
+    }
+}
+
+impl Neg for PrimeFieldElem {
+    type Output = PrimeFieldElem;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl Neg for &PrimeFieldElem {
+    type Output = PrimeFieldElem;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl AddAssign<&Self> for PrimeFieldElem {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.val = self.ctx.add(self.val, rhs.val);
+    }
+}
+
+impl AddFrom<&Self> for PrimeFieldElem {
+    #[inline]
+    fn add_from(&mut self, lhs: &Self) {
+        self.val = self.ctx.add(lhs.val, self.val);
+    }
+}
+
+forward_binop! {
+    PrimeFieldElem
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+impl SubAssign<&Self> for PrimeFieldElem {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.val = self.ctx.sub(self.val, rhs.val);
+    }
+}
+
+impl SubFrom<&Self> for PrimeFieldElem {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        self.val = self.ctx.sub(lhs.val, self.val);
+    }
+}
+
+forward_binop! {
+    PrimeFieldElem
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+impl MulAssign<&Self> for PrimeFieldElem {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.val = self.ctx.mul(self.val, rhs.val);
+    }
+}
+
+impl MulFrom<&Self> for PrimeFieldElem {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        self.val = self.ctx.mul(lhs.val, self.val);
+    }
+}
+
+forward_binop! {
+    PrimeFieldElem
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
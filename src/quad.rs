@@ -0,0 +1,546 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::traits::{DetRing, EuclideanRing, ExactDivisionRing, GcdRing, InvertibleRing};
+use crate::util::{check_unital, ring_integer};
+
+mod ops;
+
+///////////////////////////////////////////////////////////////////
+// GenericQuadRing<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct GenericQuadCtx<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) base_ring: T,
+    pub(crate) d: Elem<T>,
+}
+
+impl<T: Ring + fmt::Debug> fmt::Debug for GenericQuadCtx<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GenericQuadCtx")
+            .field("base_ring", &self.base_ring)
+            .finish()
+    }
+}
+
+/// The quadratic extension `R[s]/(s^2 - d)` of a commutative ring `R`, with
+/// elements `a + b*s` where `s` is a square root of `d`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericQuadRing<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericQuadCtx<T>>,
+}
+
+impl<T: Ring> GenericQuadRing<T> {
+    /// The ring `R[sqrt(d)]`. Panics if the base ring has no identity, e.g.
+    /// a non-square matrix space.
+    #[inline]
+    pub fn init(base_ring: &T, d: &Elem<T>) -> Self {
+        check_unital(base_ring);
+        GenericQuadRing {
+            ctx: Rc::new(GenericQuadCtx {
+                base_ring: base_ring.clone(),
+                d: d.clone(),
+            })
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        &self.ctx.base_ring
+    }
+
+    /// The element `d` whose square root is adjoined.
+    #[inline]
+    pub fn discriminant(&self) -> &Elem<T> {
+        &self.ctx.d
+    }
+
+    /// The element `a + b*sqrt(d)`.
+    #[inline]
+    pub fn from_parts(&self, a: Elem<T>, b: Elem<T>) -> GenericQuad<T> {
+        GenericQuad {
+            ctx: Rc::clone(&self.ctx),
+            a,
+            b,
+        }
+    }
+
+    /// The element `a`.
+    #[inline]
+    pub fn from_base(&self, a: Elem<T>) -> GenericQuad<T> {
+        self.from_parts(a, self.base_ring().zero())
+    }
+
+    /// The square root `sqrt(d)`.
+    #[inline]
+    pub fn gen(&self) -> GenericQuad<T> {
+        self.from_parts(self.base_ring().zero(), self.base_ring().one())
+    }
+}
+
+impl<T: Ring> NewElement<&GenericQuad<T>> for GenericQuadRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericQuad<T>) -> GenericQuad<T> {
+        val.clone()
+    }
+}
+
+impl<S, T> NewElement<(S, S)> for GenericQuadRing<T>
+where
+    T: Ring + NewElement<S>,
+{
+    #[inline]
+    fn new(&self, val: (S, S)) -> GenericQuad<T> {
+        let a = self.base_ring().new(val.0);
+        let b = self.base_ring().new(val.1);
+        self.from_parts(a, b)
+    }
+}
+
+impl<T: Ring + fmt::Display> fmt::Display for GenericQuadRing<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} adjoin sqrt({})", self.base_ring(), self.discriminant())
+    }
+}
+
+impl<T: Ring + PartialEq> Eq for GenericQuadRing<T> {}
+
+impl<T: Ring + PartialEq> PartialEq for GenericQuadRing<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericQuadRing<T>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) ||
+            (self.base_ring() == rhs.base_ring() && self.discriminant() == rhs.discriminant())
+    }
+}
+
+impl<T: Ring + Hash> Hash for GenericQuadRing<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base_ring().hash(state);
+        self.discriminant().hash(state);
+    }
+}
+
+impl<T: Ring> Parent for GenericQuadRing<T> {
+    type Element = GenericQuad<T>;
+}
+
+impl<T: Ring> Identity<Additive> for GenericQuadRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericQuad<T> {
+        self.from_base(self.base_ring().zero())
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for GenericQuadRing<T> {}
+
+impl<T: Ring> Associative<Additive> for GenericQuadRing<T> {}
+
+impl<T: Ring> Commutative<Additive> for GenericQuadRing<T> {}
+
+impl<T: Ring> Identity<Multiplicative> for GenericQuadRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericQuad<T> {
+        self.from_base(self.base_ring().one())
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for GenericQuadRing<T> {}
+
+impl<T: Ring> Commutative<Multiplicative> for GenericQuadRing<T> {}
+
+impl<T: Ring> Distributive for GenericQuadRing<T> {}
+
+/// Berkowitz's algorithm, since `d` may be a square in the base ring, making
+/// this ring not an integral domain.
+impl<T: Ring> DetRing for GenericQuadRing<T> {}
+
+impl<T: InvertibleRing> InvertibleRing for GenericQuadRing<T> {
+    #[inline]
+    fn inverse(&self, a: &GenericQuad<T>) -> Option<GenericQuad<T>> {
+        a.inv()
+    }
+}
+
+/// Exact division `x/y = x*conj(y)/norm(y)`, valid when the norm of a
+/// nonzero element is nonzero, i.e. when `d` is not a square in `R`.
+impl<T: ExactDivisionRing> ExactDivisionRing for GenericQuadRing<T> {
+    fn divexact(&self, x: &GenericQuad<T>, y: &GenericQuad<T>) -> Option<GenericQuad<T>> {
+        let ring = self.base_ring();
+        let n = y.norm();
+        if n.is_zero() {
+            return None;
+        }
+        let z = x * &y.conjugate();
+        let a = ring.divexact(&z.a, &n)?;
+        let b = ring.divexact(&z.b, &n)?;
+        Some(self.from_parts(a, b))
+    }
+}
+
+impl<T: EuclideanRing> GenericQuadRing<T> {
+    /// The discriminant as one of the integers `-2, -1, 2, 3`, for which the
+    /// absolute norm is a Euclidean function on `Z[sqrt(d)]`, or `None`.
+    pub fn euclidean_discriminant(&self) -> Option<i64> {
+        let ring = self.base_ring();
+        [-2, -1, 2, 3].into_iter().find(|&k: &i64| {
+            let mut n = ring_integer(ring, k.unsigned_abs());
+            if k < 0 {
+                n.neg_assign();
+            }
+            &n == self.discriminant()
+        })
+    }
+
+    // the fundamental unit 1 + sqrt(2) or 2 + sqrt(3) for d = 2, 3
+    fn fundamental_unit(&self, d: i64) -> GenericQuad<T> {
+        let ring = self.base_ring();
+        self.from_parts(ring_integer(ring, d as u64 - 1), ring.one())
+    }
+}
+
+/// Division with remainder by `GenericQuad::divrem`. Panics unless the
+/// discriminant is one of `-2, -1, 2, 3`, see `euclidean_discriminant`.
+impl<T: EuclideanRing> EuclideanRing for GenericQuadRing<T> {
+    #[inline]
+    fn divrem(
+        &self,
+        a: &GenericQuad<T>,
+        b: &GenericQuad<T>
+    ) -> Option<(GenericQuad<T>, GenericQuad<T>)> {
+        assert!(
+            self.euclidean_discriminant().is_some(),
+            "Discriminant must be one of -2, -1, 2, 3."
+        );
+        a.divrem(b)
+    }
+}
+
+// whether a is nonnegative, taking the canonical unit in the base ring as
+// the sign
+fn is_nonneg<T: GcdRing>(ring: &T, a: &Elem<T>) -> bool {
+    ring.canonical_unit(a).is_one()
+}
+
+/// Greatest common divisors by the Euclidean algorithm, for the same
+/// discriminants as `EuclideanRing`, with the base ring ordered by the sign
+/// given by its canonical units.
+impl<T: EuclideanRing + GcdRing> GcdRing for GenericQuadRing<T> {
+    /// The gcd with canonical unit one.
+    fn gcd(&self, a: &GenericQuad<T>, b: &GenericQuad<T>) -> GenericQuad<T> {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        while !b.is_zero() {
+            let (_, r) = self.divrem(&a, &b).unwrap();
+            a = b;
+            b = r;
+        }
+        let u = self.canonical_unit(&a);
+        self.divexact(&a, &u).unwrap()
+    }
+
+    /// The unit `u` for which `a/u = x + y*sqrt(d)` has `x > 0, y >= 0` for
+    /// `d = -1`, a positive first nonzero part for `d = -2`, and for `d = 2, 3`
+    /// has `x, y >= 0` and `|x + y*sqrt(d)|/|x - y*sqrt(d)|` in `[1, e^2)`,
+    /// where `e` is the fundamental unit.
+    fn canonical_unit(&self, a: &GenericQuad<T>) -> GenericQuad<T> {
+        let ring = self.base_ring();
+        let d = self.euclidean_discriminant()
+            .expect("Discriminant must be one of -2, -1, 2, 3.");
+        let mut u = self.one();
+        if a.is_zero() {
+            return u;
+        }
+        let mut c = a.clone();
+        match d {
+            -1 => {
+                // rotate by powers of i into the first quadrant
+                let i = self.gen();
+                let i_inv = i.conjugate();
+                while c.a.is_zero() || !is_nonneg(ring, &c.a) || !is_nonneg(ring, &c.b) {
+                    c = &c * &i_inv;
+                    u = &u * &i;
+                }
+            },
+            -2 => {},
+            _ => {
+                // |x + y*sqrt(d)| >= |x - y*sqrt(d)| exactly when x*y >= 0,
+                // and multiplying by e scales their ratio by e^2
+                let e = self.fundamental_unit(d);
+                let e_inv = self.divexact(&self.one(), &e).unwrap();
+                let ratio_at_least_one = |c: &GenericQuad<T>| {
+                    let mut t = c.a.clone();
+                    t.mul_assign(&c.b);
+                    is_nonneg(ring, &t)
+                };
+                loop {
+                    if !ratio_at_least_one(&c) {
+                        c = &c * &e;
+                        u = &u * &e_inv;
+                    } else {
+                        let next = &c * &e_inv;
+                        if !ratio_at_least_one(&next) {
+                            break;
+                        }
+                        c = next;
+                        u = &u * &e;
+                    }
+                }
+            },
+        }
+        let first = if c.a.is_zero() { &c.b } else { &c.a };
+        if !is_nonneg(ring, first) {
+            u.neg_assign();
+        }
+        u
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// GenericQuad<T>
+///////////////////////////////////////////////////////////////////
+
+/// An element `a + b*sqrt(d)`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericQuad<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericQuadCtx<T>>,
+    pub(crate) a: Elem<T>,
+    pub(crate) b: Elem<T>,
+}
+
+impl<T: Ring> fmt::Display for GenericQuad<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = format!("sqrt({})", self.ctx.d);
+        if self.b.is_zero() {
+            write!(f, "{}", self.a)
+        } else {
+            let bs = if self.b.is_one() { s } else { format!("{}*{}", self.b, s) };
+            if self.a.is_zero() {
+                write!(f, "{}", bs)
+            } else {
+                write!(f, "{} + {}", self.a, bs)
+            }
+        }
+    }
+}
+
+impl<T: Ring> Eq for GenericQuad<T> {}
+
+impl<T: Ring> PartialEq for GenericQuad<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericQuad<T>) -> bool {
+        self.a == rhs.a && self.b == rhs.b
+    }
+}
+
+impl<T: Ring + Hash> Hash for GenericQuad<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent().hash(state);
+        self.a.hash(state);
+        self.b.hash(state);
+    }
+}
+
+impl<T: Ring> Element for GenericQuad<T> {
+    type Parent = GenericQuadRing<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericQuadRing<T> {
+        GenericQuadRing {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: Ring> Operation<Additive> for GenericQuad<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for GenericQuad<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero()
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for GenericQuad<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: Ring> Operation<Multiplicative> for GenericQuad<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for GenericQuad<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.a.is_one() && self.b.is_zero()
+    }
+}
+
+impl<T: Ring> GenericQuad<T> {
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        &self.ctx.base_ring
+    }
+
+    /// The rational part `a`.
+    #[inline]
+    pub fn real_part(&self) -> &Elem<T> {
+        &self.a
+    }
+
+    /// The coefficient `b` of `sqrt(d)`.
+    #[inline]
+    pub fn sqrt_part(&self) -> &Elem<T> {
+        &self.b
+    }
+
+    #[inline]
+    pub fn into_parts(self) -> (Elem<T>, Elem<T>) {
+        (self.a, self.b)
+    }
+
+    /// The conjugate `a - b*sqrt(d)`.
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        let mut res = self.clone();
+        res.b.neg_assign();
+        res
+    }
+
+    /// The norm `a^2 - d*b^2`.
+    pub fn norm(&self) -> Elem<T> {
+        let mut res = self.a.clone();
+        res.mul_assign(&self.a);
+        let mut t = self.b.clone();
+        t.mul_assign(&self.b);
+        t.mul_assign(&self.ctx.d);
+        res.sub_assign(&t);
+        res
+    }
+
+    /// The trace `2*a`.
+    #[inline]
+    pub fn trace(&self) -> Elem<T> {
+        let mut res = self.a.clone();
+        res.add_assign(&self.a);
+        res
+    }
+
+    /// Raise to the power `e` by repeated squaring.
+    pub fn pow_ui(&self, e: u64) -> Self {
+        let mut res = self.parent().one();
+        for i in (0..64 - e.leading_zeros()).rev() {
+            res = &res * &res;
+            if (e >> i) & 1 == 1 {
+                res = &res * self;
+            }
+        }
+        res
+    }
+}
+
+impl<T: InvertibleRing> GenericQuad<T> {
+    /// The inverse `conj/norm`, which exists exactly when the norm is a
+    /// unit.
+    pub fn inv(&self) -> Option<Self> {
+        let n = self.base_ring().inverse(&self.norm())?;
+        let mut res = self.conjugate();
+        res.a.mul_assign(&n);
+        res.b.mul_assign(&n);
+        Some(res)
+    }
+
+    /// Whether `self` is a unit, i.e. its norm is a unit.
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        self.base_ring().is_unit(&self.norm())
+    }
+}
+
+impl<T: EuclideanRing> GenericQuad<T> {
+    /// Division with remainder, rounding each part of `self*conj(rhs)/norm(rhs)`
+    /// to a nearest element of the base ring. Over the integers the remainder
+    /// has smaller absolute norm than `rhs` for `d` in `{-2, -1, 2, 3}`, e.g.
+    /// in the Gaussian integers. Returns `None` if the norm of `rhs` is zero.
+    pub fn divrem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        let ring = self.base_ring();
+        let n = rhs.norm();
+        let z = self * &rhs.conjugate();
+        let (qa, _) = ring.divrem(&z.a, &n)?;
+        let (qb, _) = ring.divrem(&z.b, &n)?;
+        let q = self.parent().from_parts(qa, qb);
+        let r = self - &(&q * rhs);
+        Some((q, r))
+    }
+}
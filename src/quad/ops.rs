@@ -0,0 +1,142 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::quad::GenericQuad;
+
+// Neg
+impl<T: Ring> NegAssign for GenericQuad<T> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.a.neg_assign();
+        self.b.neg_assign();
+    }
+}
+
+impl<T: Ring> Neg for GenericQuad<T> {
+    type Output = GenericQuad<T>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: Ring> Neg for &GenericQuad<T> {
+    type Output = GenericQuad<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl<T: Ring> AddAssign<&Self> for GenericQuad<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.a.add_assign(&rhs.a);
+        self.b.add_assign(&rhs.b);
+    }
+}
+
+impl<T: Ring> AddFrom<&Self> for GenericQuad<T> {
+    #[inline]
+    fn add_from(&mut self, lhs: &Self) {
+        self.a.add_from(&lhs.a);
+        self.b.add_from(&lhs.b);
+    }
+}
+
+forward_binop! {
+    GenericQuad<T: Ring>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+impl<T: Ring> SubAssign<&Self> for GenericQuad<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.a.sub_assign(&rhs.a);
+        self.b.sub_assign(&rhs.b);
+    }
+}
+
+impl<T: Ring> SubFrom<&Self> for GenericQuad<T> {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        self.a.sub_from(&lhs.a);
+        self.b.sub_from(&lhs.b);
+    }
+}
+
+forward_binop! {
+    GenericQuad<T: Ring>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+impl<T: Ring> GenericQuad<T> {
+    // (a1 + b1*s)(a2 + b2*s) = (a1*a2 + d*b1*b2) + (a1*b2 + b1*a2)*s
+    fn mul_parts(&mut self, lhs: &Self, rhs: &Self) {
+        let mut a = lhs.a.clone();
+        a.mul_assign(&rhs.a);
+        let mut t = lhs.b.clone();
+        t.mul_assign(&rhs.b);
+        t.mul_assign(&self.ctx.d);
+        a.add_assign(&t);
+
+        let mut b = lhs.a.clone();
+        b.mul_assign(&rhs.b);
+        let mut t = lhs.b.clone();
+        t.mul_assign(&rhs.a);
+        b.add_assign(&t);
+
+        self.a = a;
+        self.b = b;
+    }
+}
+
+impl<T: Ring> MulAssign<&Self> for GenericQuad<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        let lhs = self.clone();
+        self.mul_parts(&lhs, rhs);
+    }
+}
+
+impl<T: Ring> MulFrom<&Self> for GenericQuad<T> {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        let rhs = self.clone();
+        self.mul_parts(lhs, &rhs);
+    }
+}
+
+forward_binop! {
+    GenericQuad<T: Ring>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
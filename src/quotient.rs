@@ -0,0 +1,460 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::poly::{GenericPoly, GenericPolyRing};
+use crate::traits::{DetRing, InvertibleRing};
+use crate::util::check_unital;
+
+mod charpoly;
+mod ops;
+
+///////////////////////////////////////////////////////////////////
+// GenericPolyQuotientRing<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct GenericPolyQuotientCtx<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) poly_ring: GenericPolyRing<T>,
+    pub(crate) modulus: GenericPoly<T>,
+}
+
+impl<T: Ring + fmt::Debug> fmt::Debug for GenericPolyQuotientCtx<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GenericPolyQuotientCtx")
+            .field("poly_ring", &self.poly_ring)
+            .field("degree", &self.modulus.deg())
+            .finish()
+    }
+}
+
+impl<T: InvertibleRing> GenericPolyQuotientCtx<T> {
+    pub fn new(poly_ring: GenericPolyRing<T>, modulus: &GenericPoly<T>) -> Self {
+        let mut modulus = modulus.clone();
+        modulus.normalize();
+        let modulus = match modulus.deg() {
+            Some(d) if d > 0 => modulus.make_monic(),
+            _ => None,
+        };
+        GenericPolyQuotientCtx {
+            poly_ring,
+            modulus: modulus.expect(
+                "Modulus must have positive degree and unit leading coefficient."
+            ),
+        }
+    }
+}
+
+/// The quotient ring `R[x]/(f)` of a univariate polynomial ring by a
+/// polynomial `f` of positive degree with unit leading coefficient.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericPolyQuotientRing<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericPolyQuotientCtx<T>>,
+}
+
+impl<T: InvertibleRing> GenericPolyQuotientRing<T> {
+    /// The quotient of `poly_ring` by `modulus`, which is made monic. Panics
+    /// if `modulus` is constant or its leading coefficient is not a unit, or
+    /// if the base ring has no identity.
+    #[inline]
+    pub fn init(poly_ring: &GenericPolyRing<T>, modulus: &GenericPoly<T>) -> Self {
+        check_unital(poly_ring.base_ring());
+        GenericPolyQuotientRing {
+            ctx: Rc::new(GenericPolyQuotientCtx::new(poly_ring.clone(), modulus))
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.poly_ring.base_ring()
+    }
+
+    #[inline]
+    pub fn poly_ring(&self) -> &GenericPolyRing<T> {
+        &self.ctx.poly_ring
+    }
+
+    /// The monic modulus `f`.
+    #[inline]
+    pub fn modulus(&self) -> &GenericPoly<T> {
+        &self.ctx.modulus
+    }
+
+    /// The degree of the modulus, i.e. the rank over the base ring.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.ctx.modulus.len() - 1
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.poly_ring.var()
+    }
+
+    /// The residue class of `poly`.
+    pub fn from_poly(&self, poly: GenericPoly<T>) -> GenericPolyQuotient<T> {
+        let mut res = GenericPolyQuotient {
+            ctx: Rc::clone(&self.ctx),
+            poly,
+        };
+        res.reduce();
+        res
+    }
+
+    /// The residue class of `x`.
+    pub fn gen(&self) -> GenericPolyQuotient<T> {
+        let mut x = self.poly_ring().zero();
+        x.set_coefficient(1, self.base_ring().one());
+        self.from_poly(x)
+    }
+}
+
+impl<T: InvertibleRing> NewElement<&GenericPolyQuotient<T>> for GenericPolyQuotientRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericPolyQuotient<T>) -> GenericPolyQuotient<T> {
+        val.clone()
+    }
+}
+
+impl<T: InvertibleRing> NewElement<&GenericPoly<T>> for GenericPolyQuotientRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericPoly<T>) -> GenericPolyQuotient<T> {
+        self.from_poly(val.clone())
+    }
+}
+
+impl<T: InvertibleRing> NewElement<GenericPoly<T>> for GenericPolyQuotientRing<T> {
+    #[inline]
+    fn new(&self, val: GenericPoly<T>) -> GenericPolyQuotient<T> {
+        self.from_poly(val)
+    }
+}
+
+impl<S, T, const CAP: usize> NewElement<[S; CAP]> for GenericPolyQuotientRing<T>
+where
+    T: InvertibleRing + NewElement<S>,
+{
+    #[inline]
+    fn new(&self, coeffs: [S; CAP]) -> GenericPolyQuotient<T> {
+        self.from_poly(self.poly_ring().new(coeffs))
+    }
+}
+
+impl<S, T> NewElement<Vec<S>> for GenericPolyQuotientRing<T>
+where
+    T: InvertibleRing + Ring<Element=S>
+{
+    #[inline]
+    fn new(&self, coeffs: Vec<S>) -> GenericPolyQuotient<T> {
+        let mut poly = self.poly_ring().new(coeffs);
+        poly.normalize();
+        self.from_poly(poly)
+    }
+}
+
+impl<T: InvertibleRing + fmt::Display> fmt::Display for GenericPolyQuotientRing<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Quotient of {} by {}", self.poly_ring(), self.modulus())
+    }
+}
+
+impl<T: InvertibleRing + PartialEq> Eq for GenericPolyQuotientRing<T> {}
+
+impl<T: InvertibleRing + PartialEq> PartialEq for GenericPolyQuotientRing<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericPolyQuotientRing<T>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) ||
+            (self.poly_ring() == rhs.poly_ring() && self.modulus() == rhs.modulus())
+    }
+}
+
+impl<T: InvertibleRing + Hash> Hash for GenericPolyQuotientRing<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.poly_ring().hash(state);
+        self.modulus().coefficients().hash(state);
+    }
+}
+
+impl<T: InvertibleRing> Parent for GenericPolyQuotientRing<T> {
+    type Element = GenericPolyQuotient<T>;
+}
+
+impl<T: InvertibleRing> Identity<Additive> for GenericPolyQuotientRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericPolyQuotient<T> {
+        self.from_poly(self.poly_ring().zero())
+    }
+}
+
+impl<T: InvertibleRing> Divisible<Additive> for GenericPolyQuotientRing<T> {}
+
+impl<T: InvertibleRing> Associative<Additive> for GenericPolyQuotientRing<T> {}
+
+impl<T: InvertibleRing> Commutative<Additive> for GenericPolyQuotientRing<T> {}
+
+impl<T: InvertibleRing> Identity<Multiplicative> for GenericPolyQuotientRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericPolyQuotient<T> {
+        self.from_poly(self.poly_ring().one())
+    }
+}
+
+impl<T: InvertibleRing> Associative<Multiplicative> for GenericPolyQuotientRing<T> {}
+
+impl<T: InvertibleRing> Commutative<Multiplicative> for GenericPolyQuotientRing<T> {}
+
+impl<T: InvertibleRing> Distributive for GenericPolyQuotientRing<T> {}
+
+impl<T: InvertibleRing> DetRing for GenericPolyQuotientRing<T> {}
+
+impl<T: InvertibleRing> InvertibleRing for GenericPolyQuotientRing<T> {
+    #[inline]
+    fn inverse(&self, a: &GenericPolyQuotient<T>) -> Option<GenericPolyQuotient<T>> {
+        a.inv()
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// GenericPolyQuotient<T>
+///////////////////////////////////////////////////////////////////
+
+/// A residue class in `R[x]/(f)`, represented by its remainder modulo `f`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericPolyQuotient<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericPolyQuotientCtx<T>>,
+    pub(crate) poly: GenericPoly<T>,
+}
+
+impl<T: InvertibleRing> fmt::Display for GenericPolyQuotient<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.poly)
+    }
+}
+
+impl<T: InvertibleRing> Eq for GenericPolyQuotient<T> {}
+
+impl<T: InvertibleRing> PartialEq for GenericPolyQuotient<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericPolyQuotient<T>) -> bool {
+        self.poly == rhs.poly
+    }
+}
+
+impl<T: InvertibleRing + Hash> Hash for GenericPolyQuotient<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent().hash(state);
+        self.poly.coefficients().hash(state);
+    }
+}
+
+impl<T: InvertibleRing> Element for GenericPolyQuotient<T> {
+    type Parent = GenericPolyQuotientRing<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericPolyQuotientRing<T> {
+        GenericPolyQuotientRing {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: InvertibleRing> Operation<Additive> for GenericPolyQuotient<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: InvertibleRing> IsIdentity<Additive> for GenericPolyQuotient<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.poly.deg().is_none()
+    }
+}
+
+impl<T: InvertibleRing> TwoSidedInverse<Additive> for GenericPolyQuotient<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: InvertibleRing> Operation<Multiplicative> for GenericPolyQuotient<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: InvertibleRing> IsIdentity<Multiplicative> for GenericPolyQuotient<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.poly.len() == 1 && self.poly.coefficients()[0].is_one()
+    }
+}
+
+impl<T: InvertibleRing> GenericPolyQuotient<T> {
+    // replace poly by its remainder modulo the modulus
+    pub(crate) fn reduce(&mut self) {
+        self.poly.normalize();
+        if self.poly.len() > self.ctx.modulus.len() - 1 {
+            let (_, r) = self.poly.divrem(&self.ctx.modulus).unwrap();
+            self.poly = r;
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.poly_ring.base_ring()
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.poly_ring.var()
+    }
+
+    /// The reduced representative, of degree less than that of the modulus.
+    #[inline]
+    pub fn lift(&self) -> &GenericPoly<T> {
+        &self.poly
+    }
+
+    #[inline]
+    pub fn into_poly(self) -> GenericPoly<T> {
+        self.poly
+    }
+
+    /// Raise to the power `e` by repeated squaring.
+    pub fn pow_ui(&self, e: u64) -> Self {
+        let mut res = self.parent().from_poly(self.ctx.poly_ring.one());
+        for i in (0..64 - e.leading_zeros()).rev() {
+            res = &res * &res;
+            if (e >> i) & 1 == 1 {
+                res = &res * self;
+            }
+        }
+        res
+    }
+
+    /// The inverse, or `None` if the residue class is not a unit.
+    ///
+    /// Uses the extended Euclidean algorithm while the remainders have unit
+    /// leading coefficients, as they always do over a field. Otherwise, e.g.
+    /// over `Zmod(n)` with `n` composite, `self` is a unit exactly when its
+    /// norm is, and the inverse comes from the Cayley-Hamilton theorem.
+    pub fn inv(&self) -> Option<Self> {
+        match self.inv_euclidean() {
+            Some(res) => res,
+            None => self.inv_charpoly(),
+        }
+    }
+
+    /// Whether the residue class is a unit.
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        self.inv().is_some()
+    }
+
+    // the inverse by the extended Euclidean algorithm on the modulus and
+    // self, or None if a remainder has a leading coefficient which is not a
+    // unit
+    fn inv_euclidean(&self) -> Option<Option<Self>> {
+        let parent = self.ctx.poly_ring.clone();
+        let (mut r0, mut r1) = (self.ctx.modulus.clone(), self.poly.clone());
+        let (mut t0, mut t1) = (parent.zero(), parent.one());
+        r1.normalize();
+
+        while r1.deg().is_some() {
+            let (q, r) = r0.divrem(&r1)?;
+            let t = &t0 - &q * &t1;
+            r0 = std::mem::replace(&mut r1, r);
+            t0 = std::mem::replace(&mut t1, t);
+        }
+
+        // r0 is a common divisor of self and the modulus with unit leading
+        // coefficient, so self is a unit exactly when r0 is constant
+        if r0.deg() != Some(0) {
+            return Some(None);
+        }
+        let inv = self.base_ring().inverse(&r0.coefficients()[0])?;
+        Some(Some(self.parent().from_poly(t0.scalar_mul(&inv))))
+    }
+
+    // the inverse -(c_1 + c_2*a + ... + c_n*a^(n-1))/c_0 from the
+    // characteristic polynomial c_0 + c_1*t + ... + c_n*t^n of a = self
+    fn inv_charpoly(&self) -> Option<Self> {
+        let chi = self.charpoly();
+        let c = chi.coefficients();
+        let mut c0 = self.base_ring().inverse(&c[0])?;
+        c0.neg_assign();
+
+        let parent = self.parent();
+        let mut res = parent.zero();
+        for ck in c.iter().skip(1).rev() {
+            res.mul_assign(self);
+            res.add_assign(&parent.new(vec![ck.clone()]));
+        }
+        Some(parent.from_poly(res.poly.scalar_mul(&c0)))
+    }
+}
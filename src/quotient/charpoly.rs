@@ -0,0 +1,146 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::rc::Rc;
+
+use crate::mat::{berkowitz, GenericMat, GenericMatSpace};
+use crate::poly::GenericPoly;
+use crate::quotient::GenericPolyQuotient;
+use crate::traits::{DivisionRing, InvertibleRing};
+
+impl<T: InvertibleRing> GenericPolyQuotient<T> {
+    // the coordinates of self*x^j in the basis 1, x, ..., x^(n-1) for each j
+    fn multiplication_rows(&self) -> Vec<Elem<T>> {
+        let n = self.parent().degree();
+        let zero = self.base_ring().zero();
+        let x = self.parent().gen();
+
+        let mut rows = Vec::with_capacity(n*n);
+        let mut b = self.clone();
+        for j in 0..n {
+            if j > 0 {
+                b.mul_assign(&x);
+            }
+            let c = b.poly.coefficients();
+            rows.extend((0..n).map(|i| c.get(i).unwrap_or(&zero).clone()));
+        }
+        rows
+    }
+
+    /// The matrix of multiplication by `self` on the basis `1, x, ..., x^(n-1)`
+    /// acting on row vectors, so row `j` holds the coordinates of `self*x^j`.
+    pub fn multiplication_matrix(&self) -> GenericMat<T> {
+        let n = self.parent().degree() as u64;
+        let space = GenericMatSpace::init(self.base_ring(), n, n);
+        GenericMat {
+            ctx: Rc::clone(&space.ctx),
+            entries: self.multiplication_rows(),
+        }
+    }
+
+    /// The characteristic polynomial of multiplication by `self`, in the
+    /// polynomial ring of the quotient.
+    pub fn charpoly(&self) -> GenericPoly<T> {
+        let n = self.parent().degree();
+        let coeffs = berkowitz(self.base_ring(), &self.multiplication_rows(), n);
+        let mut res = self.ctx.poly_ring.new(coeffs);
+        res.normalize();
+        res
+    }
+
+    /// The norm, i.e. the determinant of multiplication by `self`.
+    pub fn norm(&self) -> Elem<T> {
+        let n = self.parent().degree();
+        let mut res = self.charpoly().coefficients()[0].clone();
+        if n % 2 == 1 {
+            res.neg_assign();
+        }
+        res
+    }
+
+    /// The trace of multiplication by `self`.
+    pub fn trace(&self) -> Elem<T> {
+        let n = self.parent().degree();
+        let rows = self.multiplication_rows();
+        let mut res = self.base_ring().zero();
+        for i in 0..n {
+            res.add_assign(&rows[i*n + i]);
+        }
+        res
+    }
+}
+
+impl<T: DivisionRing> GenericPolyQuotient<T> {
+    /// The minimal polynomial, found as the first linear dependency among
+    /// the powers `1, self, self^2, ...` over the base field.
+    pub fn minpoly(&self) -> GenericPoly<T> {
+        let ring = self.base_ring();
+        let n = self.parent().degree();
+        let zero = ring.zero();
+
+        // echelonized coordinate vectors of earlier powers with pivot one,
+        // each with its expression as a combination of those powers
+        let mut basis: Vec<(usize, Vec<Elem<T>>, Vec<Elem<T>>)> = Vec::new();
+        let mut power = self.parent().from_poly(self.ctx.poly_ring.one());
+
+        for k in 0..=n {
+            let c = power.poly.coefficients();
+            let mut v: Vec<Elem<T>> = (0..n)
+                .map(|i| c.get(i).unwrap_or(&zero).clone())
+                .collect();
+            let mut comb = vec![ring.zero(); k + 1];
+            comb[k] = ring.one();
+
+            for (p, row, rc) in basis.iter() {
+                if v[*p].is_zero() {
+                    continue;
+                }
+                let f = v[*p].clone();
+                for (x, y) in v.iter_mut().zip(row.iter()) {
+                    let mut t = f.clone();
+                    t.mul_assign(y);
+                    x.sub_assign(&t);
+                }
+                for (x, y) in comb.iter_mut().zip(rc.iter()) {
+                    let mut t = f.clone();
+                    t.mul_assign(y);
+                    x.sub_assign(&t);
+                }
+            }
+
+            match v.iter().position(|x| !x.is_zero()) {
+                None => {
+                    let mut res = self.ctx.poly_ring.new(comb);
+                    res.normalize();
+                    return res;
+                },
+                Some(p) => {
+                    let inv = ring.inverse(&v[p]).unwrap();
+                    for x in v.iter_mut().chain(comb.iter_mut()) {
+                        x.mul_assign(&inv);
+                    }
+                    basis.push((p, v, comb));
+                },
+            }
+            power.mul_assign(self);
+        }
+        unreachable!()
+    }
+}
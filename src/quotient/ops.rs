@@ -0,0 +1,117 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::quotient::GenericPolyQuotient;
+use crate::traits::InvertibleRing;
+
+// Neg
+impl<T: InvertibleRing> NegAssign for GenericPolyQuotient<T> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.poly.neg_assign();
+    }
+}
+
+impl<T: InvertibleRing> Neg for GenericPolyQuotient<T> {
+    type Output = GenericPolyQuotient<T>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: InvertibleRing> Neg for &GenericPolyQuotient<T> {
+    type Output = GenericPolyQuotient<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl<T: InvertibleRing> AddAssign<&Self> for GenericPolyQuotient<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.poly.add_assign(&rhs.poly);
+    }
+}
+
+impl<T: InvertibleRing> AddFrom<&Self> for GenericPolyQuotient<T> {
+    #[inline]
+    fn add_from(&mut self, lhs: &Self) {
+        self.poly.add_from(&lhs.poly);
+    }
+}
+
+forward_binop! {
+    GenericPolyQuotient<T: InvertibleRing>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+impl<T: InvertibleRing> SubAssign<&Self> for GenericPolyQuotient<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.poly.sub_assign(&rhs.poly);
+    }
+}
+
+impl<T: InvertibleRing> SubFrom<&Self> for GenericPolyQuotient<T> {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        self.poly.sub_from(&lhs.poly);
+    }
+}
+
+forward_binop! {
+    GenericPolyQuotient<T: InvertibleRing>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+impl<T: InvertibleRing> MulAssign<&Self> for GenericPolyQuotient<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.poly.mul_assign(&rhs.poly);
+        self.reduce();
+    }
+}
+
+impl<T: InvertibleRing> MulFrom<&Self> for GenericPolyQuotient<T> {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        self.poly.mul_from(&lhs.poly);
+        self.reduce();
+    }
+}
+
+forward_binop! {
+    GenericPolyQuotient<T: InvertibleRing>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
@@ -0,0 +1,577 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::poly::{GenericPoly, GenericPolyRing};
+use crate::util::check_unital;
+
+mod elementary;
+mod lazy;
+mod ops;
+
+pub use lazy::{GenericLazySeries, GenericLazySeriesRing};
+
+/// Errors raised by operations on power series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeriesError {
+    /// The series has precision zero, so its constant term is unknown.
+    UnknownConstant,
+    /// The constant term is not a unit.
+    NonUnitConstant,
+    /// The operation requires a constant term of zero.
+    NonZeroConstant,
+    /// The operation requires a constant term of one.
+    NonOneConstant,
+    /// The integer is not invertible in the base ring.
+    NonInvertibleInteger(u64),
+}
+
+impl fmt::Display for SeriesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeriesError::UnknownConstant =>
+                write!(f, "constant term of the series is not known"),
+            SeriesError::NonUnitConstant =>
+                write!(f, "constant term of the series is not a unit"),
+            SeriesError::NonZeroConstant =>
+                write!(f, "constant term of the series must be zero"),
+            SeriesError::NonOneConstant =>
+                write!(f, "constant term of the series must be one"),
+            SeriesError::NonInvertibleInteger(n) =>
+                write!(f, "{} is not invertible in the base ring", n),
+        }
+    }
+}
+
+impl Error for SeriesError {}
+
+///////////////////////////////////////////////////////////////////
+// GenericPowerSeriesRing<T>
+///////////////////////////////////////////////////////////////////
+
+/// How the precision of a product is bounded.
+///
+/// With `Absolute` precision every element is known modulo `x^prec`, where
+/// `prec` is the precision of the parent. With `Relative` precision every
+/// element carries `prec` significant terms past its valuation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PrecisionModel {
+    Absolute,
+    Relative,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct GenericPowerSeriesCtx<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) poly_ring: GenericPolyRing<T>,
+    pub(crate) prec: usize,
+    pub(crate) model: PrecisionModel,
+}
+
+impl<T: Ring> GenericPowerSeriesCtx<T> {
+    pub fn new<V: Into<String>>(
+        base_ring: T,
+        var: V,
+        prec: usize,
+        model: PrecisionModel
+    ) -> Self {
+        GenericPowerSeriesCtx {
+            poly_ring: GenericPolyRing::init(&base_ring, var),
+            prec,
+            model
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericPowerSeriesRing<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericPowerSeriesCtx<T>>,
+}
+
+impl<T: Ring> GenericPowerSeriesRing<T> {
+    /// Initialize a power series ring with absolute precision `prec`.
+    #[inline]
+    pub fn init<S: Into<String>>(base_ring: &T, var: S, prec: usize) -> Self {
+        Self::init_with_model(base_ring, var, prec, PrecisionModel::Absolute)
+    }
+
+    /// Initialize a power series ring with relative precision `prec`.
+    #[inline]
+    pub fn init_relative<S: Into<String>>(
+        base_ring: &T,
+        var: S,
+        prec: usize
+    ) -> Self {
+        Self::init_with_model(base_ring, var, prec, PrecisionModel::Relative)
+    }
+
+    /// Initialize a power series ring with precision `prec` in `model`.
+    /// Panics if the base ring has no identity, e.g. a non-square matrix
+    /// space.
+    pub fn init_with_model<S: Into<String>>(
+        base_ring: &T,
+        var: S,
+        prec: usize,
+        model: PrecisionModel
+    ) -> Self {
+        check_unital(base_ring);
+        GenericPowerSeriesRing {
+            ctx: Rc::new(
+                GenericPowerSeriesCtx::new(base_ring.clone(), var, prec, model)
+            )
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.poly_ring.base_ring()
+    }
+
+    /// The polynomial ring used to store truncated series.
+    #[inline]
+    pub fn poly_ring(&self) -> &GenericPolyRing<T> {
+        &self.ctx.poly_ring
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.poly_ring.var()
+    }
+
+    #[inline]
+    pub fn set_var<S: Into<String>>(&mut self, var: S) {
+        self.ctx.poly_ring.ctx.var.replace(var.into());
+    }
+
+    /// The default precision of elements of the ring.
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.ctx.prec
+    }
+
+    #[inline]
+    pub fn precision_model(&self) -> PrecisionModel {
+        self.ctx.model
+    }
+
+    /// The generator `x`.
+    #[inline]
+    pub fn gen(&self) -> GenericPowerSeries<T> {
+        let mut poly = self.poly_ring().zero();
+        poly.set_coefficient(1, self.base_ring().one());
+        self.from_poly(poly, self.precision())
+    }
+
+    /// The series `f + O(x^prec)`.
+    pub fn from_poly(&self, poly: GenericPoly<T>, prec: usize) -> GenericPowerSeries<T> {
+        let mut res = GenericPowerSeries {
+            ctx: Rc::clone(&self.ctx),
+            poly,
+            prec,
+        };
+        res.reduce();
+        res
+    }
+
+    /// The zero series `O(x^prec)`.
+    #[inline]
+    pub fn big_oh(&self, prec: usize) -> GenericPowerSeries<T> {
+        self.from_poly(self.poly_ring().zero(), prec)
+    }
+}
+
+impl<T: Ring> NewElement<&GenericPowerSeries<T>> for GenericPowerSeriesRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericPowerSeries<T>) -> GenericPowerSeries<T> {
+        val.clone()
+    }
+}
+
+impl<T: Ring> NewElement<&GenericPoly<T>> for GenericPowerSeriesRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericPoly<T>) -> GenericPowerSeries<T> {
+        self.from_poly(val.clone(), self.precision())
+    }
+}
+
+impl<T: Ring> NewElement<GenericPoly<T>> for GenericPowerSeriesRing<T> {
+    #[inline]
+    fn new(&self, val: GenericPoly<T>) -> GenericPowerSeries<T> {
+        self.from_poly(val, self.precision())
+    }
+}
+
+impl<S, T, const CAP: usize> NewElement<[S; CAP]> for GenericPowerSeriesRing<T>
+where
+    T: Ring + NewElement<S>,
+{
+    #[inline]
+    fn new(&self, coeffs: [S; CAP]) -> GenericPowerSeries<T> {
+        let poly = self.poly_ring().new(coeffs);
+        self.from_poly(poly, self.precision())
+    }
+}
+
+impl<'a, S, T> NewElement<&'a [S]> for GenericPowerSeriesRing<T>
+where
+    T: Ring + NewElement<&'a S>,
+{
+    #[inline]
+    fn new(&self, coeffs: &'a [S]) -> GenericPowerSeries<T> {
+        let poly = self.poly_ring().new(coeffs);
+        self.from_poly(poly, self.precision())
+    }
+}
+
+impl<S, T> NewElement<Vec<S>> for GenericPowerSeriesRing<T>
+where
+    T: Ring<Element=S>
+{
+    #[inline]
+    fn new(&self, coeffs: Vec<S>) -> GenericPowerSeries<T> {
+        let poly = self.poly_ring().new(coeffs);
+        self.from_poly(poly, self.precision())
+    }
+}
+
+impl<T: Ring + fmt::Display> fmt::Display for GenericPowerSeriesRing<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let model = match self.precision_model() {
+            PrecisionModel::Absolute => "absolute",
+            PrecisionModel::Relative => "relative",
+        };
+        write!(
+            f,
+            "Univariate power series ring in {} over {} with {} precision {}",
+            self.var(),
+            self.base_ring(),
+            model,
+            self.precision()
+        )
+    }
+}
+
+impl<T: Ring + PartialEq> Eq for GenericPowerSeriesRing<T> {}
+
+impl<T: Ring + PartialEq> PartialEq for GenericPowerSeriesRing<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericPowerSeriesRing<T>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) ||
+            (self.base_ring() == rhs.base_ring()
+             && self.precision() == rhs.precision()
+             && self.precision_model() == rhs.precision_model())
+    }
+}
+
+impl<T: Ring + Hash> Hash for GenericPowerSeriesRing<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base_ring().hash(state);
+        self.precision().hash(state);
+        self.precision_model().hash(state);
+    }
+}
+
+impl<T: Ring> Parent for GenericPowerSeriesRing<T> {
+    type Element = GenericPowerSeries<T>;
+}
+
+impl<T: Ring> Identity<Additive> for GenericPowerSeriesRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericPowerSeries<T> {
+        self.big_oh(self.precision())
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for GenericPowerSeriesRing<T> {}
+
+impl<T: Ring> Associative<Additive> for GenericPowerSeriesRing<T> {}
+
+impl<T: Ring> Commutative<Additive> for GenericPowerSeriesRing<T> {}
+
+impl<T: Ring> Identity<Multiplicative> for GenericPowerSeriesRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericPowerSeries<T> {
+        self.from_poly(self.poly_ring().one(), self.precision())
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for GenericPowerSeriesRing<T> {}
+
+impl<T: Ring> Commutative<Multiplicative> for GenericPowerSeriesRing<T> {}
+
+impl<T: Ring> Distributive for GenericPowerSeriesRing<T> {}
+
+///////////////////////////////////////////////////////////////////
+// GenericPowerSeries<T>
+///////////////////////////////////////////////////////////////////
+
+/// A truncated power series `f + O(x^prec)`, where `f` is a polynomial of
+/// length at most `prec`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenericPowerSeries<T: Ring> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<GenericPowerSeriesCtx<T>>,
+    pub(crate) poly: GenericPoly<T>,
+    pub(crate) prec: usize,
+}
+
+impl<T: Ring> fmt::Display for GenericPowerSeries<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let x = self.var();
+
+        let mut out = Vec::with_capacity(self.poly.len() + 1);
+        for (i, c) in self.poly.terms() {
+            if i == 0 {
+                out.push(format!("{}", c));
+            } else if i == 1 && c.is_one() {
+                out.push(format!("{}", x));
+            } else if i == 1 {
+                out.push(format!("{}*{}", c, x));
+            } else if c.is_one() {
+                out.push(format!("{}^{}", x, i));
+            } else {
+                out.push(format!("{}*{}^{}", c, x, i));
+            }
+        }
+        match self.prec {
+            0 => out.push("O(1)".to_string()),
+            1 => out.push(format!("O({})", x)),
+            n => out.push(format!("O({}^{})", x, n)),
+        }
+        write!(f, "{}", out.join(" + "))
+    }
+}
+
+impl<T: Ring> Eq for GenericPowerSeries<T> {}
+
+/// Two series are equal if they have the same precision and coefficients.
+/// Use `approx_eq` to compare up to the lower of two precisions.
+impl<T: Ring> PartialEq for GenericPowerSeries<T> {
+    fn eq(&self, rhs: &GenericPowerSeries<T>) -> bool {
+        self.prec == rhs.prec && self.agrees_up_to(rhs, self.prec)
+    }
+}
+
+impl<T: Ring> Element for GenericPowerSeries<T> {
+    type Parent = GenericPowerSeriesRing<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericPowerSeriesRing<T> {
+        GenericPowerSeriesRing {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: Ring> Operation<Additive> for GenericPowerSeries<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for GenericPowerSeries<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.poly.valuation().is_none()
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for GenericPowerSeries<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: Ring> Operation<Multiplicative> for GenericPowerSeries<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for GenericPowerSeries<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.poly.len() == 1 && self.poly.coefficients()[0].is_one()
+    }
+}
+
+impl<T: Ring> GenericPowerSeries<T> {
+    // truncate to the precision and remove trailing zeros
+    pub(crate) fn reduce(&mut self) {
+        self.poly.coefficients_mut().truncate(self.prec);
+        if self.poly.len() == 0 {
+            let zero = self.base_ring().zero();
+            self.poly.coefficients_mut().push(zero);
+        }
+        self.poly.normalize();
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.poly_ring.base_ring()
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.poly_ring.var()
+    }
+
+    /// The absolute precision, i.e. the `n` in `O(x^n)`.
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.prec
+    }
+
+    // whether the first n coefficients agree
+    fn agrees_up_to(&self, rhs: &Self, n: usize) -> bool {
+        let zero = self.base_ring().zero();
+        let c1 = self.poly.coefficients();
+        let c2 = rhs.poly.coefficients();
+        (0..n).all(|i| c1.get(i).unwrap_or(&zero) == c2.get(i).unwrap_or(&zero))
+    }
+
+    /// Whether the series agree up to the lower of their precisions. This is
+    /// not transitive, so it is not the `PartialEq` relation.
+    #[inline]
+    pub fn approx_eq(&self, rhs: &Self) -> bool {
+        self.agrees_up_to(rhs, std::cmp::min(self.prec, rhs.prec))
+    }
+
+    /// The number of known terms past the valuation.
+    #[inline]
+    pub fn relative_precision(&self) -> usize {
+        self.prec - self.valuation()
+    }
+
+    /// The x-adic valuation. A series with no nonzero known terms has
+    /// valuation equal to its precision.
+    #[inline]
+    pub fn valuation(&self) -> usize {
+        self.poly.valuation().unwrap_or(self.prec)
+    }
+
+    /// The coefficient of `x^i`, or `None` if it is not known.
+    pub fn get_coefficient(&self, i: usize) -> Option<Elem<T>> {
+        if i >= self.prec {
+            None
+        } else if let Some(c) = self.poly.coefficient(i) {
+            Some(c.clone())
+        } else {
+            Some(self.base_ring().zero())
+        }
+    }
+
+    /// The known coefficients. Trailing zeros are omitted.
+    #[inline]
+    pub fn coefficients(&self) -> &Vec<Elem<T>> {
+        self.poly.coefficients()
+    }
+
+    /// Reduce the precision to at most `prec`.
+    #[inline]
+    pub fn truncate(&mut self, prec: usize) {
+        if prec < self.prec {
+            self.prec = prec;
+            self.reduce();
+        }
+    }
+
+    /// Return a copy with precision at most `prec`.
+    #[inline]
+    pub fn truncated(&self, prec: usize) -> Self {
+        let mut res = self.clone();
+        res.truncate(prec);
+        res
+    }
+
+    /// Multiply by `x^k`.
+    pub fn shift_left(&self, k: usize) -> Self {
+        let mut res = self.clone();
+        if res.poly.valuation().is_some() {
+            let zero = self.base_ring().zero();
+            res.poly.coefficients_mut().splice(0..0, std::iter::repeat(zero).take(k));
+        }
+        res.prec += k;
+        res
+    }
+
+    /// Divide by `x^k`, discarding the terms of degree less than `k`.
+    pub fn shift_right(&self, k: usize) -> Self {
+        let mut res = self.clone();
+        let k = std::cmp::min(k, res.prec);
+        let coeffs = res.poly.coefficients_mut();
+        coeffs.drain(0..std::cmp::min(k, coeffs.len()));
+        res.prec -= k;
+        res.reduce();
+        res
+    }
+
+    /// The known part of the series as a polynomial.
+    #[inline]
+    pub fn as_poly(&self) -> &GenericPoly<T> {
+        &self.poly
+    }
+
+    #[inline]
+    pub fn to_poly(&self) -> GenericPoly<T> {
+        self.poly.clone()
+    }
+
+    #[inline]
+    pub fn into_poly(self) -> GenericPoly<T> {
+        self.poly
+    }
+}
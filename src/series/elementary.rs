@@ -0,0 +1,309 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::rc::Rc;
+
+use crate::poly::GenericPoly;
+use crate::series::{GenericPowerSeries, GenericPowerSeriesRing, SeriesError};
+use crate::traits::InvertibleRing;
+use crate::util::ring_integer;
+
+// f mod x^n
+fn poly_truncate<T: Ring>(f: &GenericPoly<T>, n: usize) -> GenericPoly<T> {
+    let mut res = f.clone();
+    res.coefficients_mut().truncate(std::cmp::max(n, 1));
+    if n == 0 {
+        res.coefficients_mut()[0] = f.base_ring().zero();
+    }
+    res.normalize();
+    res
+}
+
+fn poly_constant<T: Ring>(f: &GenericPoly<T>, c: Elem<T>) -> GenericPoly<T> {
+    GenericPoly {
+        coeffs: vec![c],
+        ctx: Rc::clone(&f.ctx),
+    }
+}
+
+// 1/i in the base ring
+fn integer_inverse<T: InvertibleRing>(ring: &T, i: usize) -> Result<Elem<T>, SeriesError> {
+    ring.inverse(&ring_integer(ring, i as u64))
+        .ok_or(SeriesError::NonInvertibleInteger(i as u64))
+}
+
+// the integral of f mod x^n with zero constant term
+fn poly_integral<T: InvertibleRing>(
+    f: &GenericPoly<T>,
+    n: usize
+) -> Result<GenericPoly<T>, SeriesError> {
+    let ring = f.base_ring();
+    let mut coeffs = vec![ring.zero()];
+    for (i, c) in f.coefficients().iter().enumerate().take(n.saturating_sub(1)) {
+        let mut t = integer_inverse(ring, i + 1)?;
+        t.mul_assign(c);
+        coeffs.push(t);
+    }
+
+    let mut res = GenericPoly {
+        coeffs,
+        ctx: Rc::clone(&f.ctx),
+    };
+    res.normalize();
+    Ok(res)
+}
+
+// 1/f mod x^n by Newton iteration g <- g*(2 - f*g)
+fn poly_inv_series<T: InvertibleRing>(
+    f: &GenericPoly<T>,
+    n: usize
+) -> Result<GenericPoly<T>, SeriesError> {
+    let ring = f.base_ring();
+    let c = ring.inverse(&f.coefficients()[0])
+        .ok_or(SeriesError::NonUnitConstant)?;
+    let one = poly_constant(f, ring.one());
+
+    let mut g = poly_constant(f, c);
+    let mut k = 1;
+    while k < n {
+        k = std::cmp::min(2*k, n);
+        let mut e = f.mullow(&g, k);
+        e.sub_from(&one);
+        let t = g.mullow(&e, k);
+        g.add_assign(&t);
+    }
+    Ok(poly_truncate(&g, n))
+}
+
+// log(f) mod x^n for f with constant term one
+fn poly_log_series<T: InvertibleRing>(
+    f: &GenericPoly<T>,
+    n: usize
+) -> Result<GenericPoly<T>, SeriesError> {
+    if !f.coefficients()[0].is_one() {
+        return Err(SeriesError::NonOneConstant);
+    }
+    if n <= 1 {
+        return Ok(poly_constant(f, f.base_ring().zero()));
+    }
+    let g = poly_inv_series(f, n - 1)?;
+    let h = f.derivative().mullow(&g, n - 1);
+    poly_integral(&h, n)
+}
+
+// exp(f) mod x^n for f with constant term zero, by Newton iteration
+// g <- g*(1 + f - log(g))
+fn poly_exp_series<T: InvertibleRing>(
+    f: &GenericPoly<T>,
+    n: usize
+) -> Result<GenericPoly<T>, SeriesError> {
+    if !f.coefficients()[0].is_zero() {
+        return Err(SeriesError::NonZeroConstant);
+    }
+    let one = poly_constant(f, f.base_ring().one());
+
+    let mut g = one.clone();
+    let mut k = 1;
+    while k < n {
+        k = std::cmp::min(2*k, n);
+        let mut e = poly_truncate(f, k);
+        e.sub_assign(&poly_log_series(&g, k)?);
+        e.add_assign(&one);
+        g = g.mullow(&e, k);
+    }
+    Ok(poly_truncate(&g, n))
+}
+
+// sqrt(f) mod x^n for f with constant term one, by Newton iteration
+// g <- (g + f/g)/2
+fn poly_sqrt_series<T: InvertibleRing>(
+    f: &GenericPoly<T>,
+    n: usize
+) -> Result<GenericPoly<T>, SeriesError> {
+    if !f.coefficients()[0].is_one() {
+        return Err(SeriesError::NonOneConstant);
+    }
+    let half = integer_inverse(f.base_ring(), 2)?;
+
+    let mut g = poly_constant(f, f.base_ring().one());
+    let mut k = 1;
+    while k < n {
+        k = std::cmp::min(2*k, n);
+        let mut t = f.mullow(&poly_inv_series(&g, k)?, k);
+        t.add_assign(&g);
+        g = t.scalar_mul(&half);
+    }
+    Ok(poly_truncate(&g, n))
+}
+
+impl<T: Ring> GenericPowerSeries<T> {
+    /// The formal derivative.
+    pub fn derivative(&self) -> Self {
+        let prec = self.prec.saturating_sub(1);
+        self.parent().from_poly(self.poly.derivative(), prec)
+    }
+
+    /// Raise to the power `e` by repeated squaring.
+    pub fn pow_ui(&self, e: u64) -> Self {
+        let mut res = self.parent().one();
+        for i in (0..64 - e.leading_zeros()).rev() {
+            res = &res * &res;
+            if (e >> i) & 1 == 1 {
+                res = &res * self;
+            }
+        }
+        res
+    }
+}
+
+impl<T: InvertibleRing> GenericPowerSeries<T> {
+    // the constant term of a series of positive precision
+    fn check_constant(&self) -> Result<(), SeriesError> {
+        if self.prec == 0 {
+            Err(SeriesError::UnknownConstant)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The formal integral with zero constant term. Fails if an integer less
+    /// than the precision is not invertible in the base ring.
+    pub fn integral(&self) -> Result<Self, SeriesError> {
+        let prec = self.prec + 1;
+        let poly = poly_integral(&self.poly, prec)?;
+        Ok(self.parent().from_poly(poly, prec))
+    }
+
+    /// The multiplicative inverse. The constant term must be a unit.
+    pub fn inv(&self) -> Result<Self, SeriesError> {
+        self.check_constant()?;
+        let poly = poly_inv_series(&self.poly, self.prec)?;
+        Ok(self.parent().from_poly(poly, self.prec))
+    }
+
+    /// The logarithm. The constant term must be one.
+    pub fn log(&self) -> Result<Self, SeriesError> {
+        self.check_constant()?;
+        let poly = poly_log_series(&self.poly, self.prec)?;
+        Ok(self.parent().from_poly(poly, self.prec))
+    }
+
+    /// The exponential. The constant term must be zero.
+    pub fn exp(&self) -> Result<Self, SeriesError> {
+        self.check_constant()?;
+        let poly = poly_exp_series(&self.poly, self.prec)?;
+        Ok(self.parent().from_poly(poly, self.prec))
+    }
+
+    /// The square root with constant term one. The constant term must be
+    /// one and two must be invertible.
+    pub fn sqrt(&self) -> Result<Self, SeriesError> {
+        self.check_constant()?;
+        let poly = poly_sqrt_series(&self.poly, self.prec)?;
+        Ok(self.parent().from_poly(poly, self.prec))
+    }
+
+    /// The power `exp(alpha*log(f))`. The constant term must be one.
+    pub fn pow(&self, alpha: &Elem<T>) -> Result<Self, SeriesError> {
+        self.check_constant()?;
+        let mut poly = poly_log_series(&self.poly, self.prec)?;
+        poly.scalar_mul_assign(alpha);
+        let poly = poly_exp_series(&poly, self.prec)?;
+        Ok(self.parent().from_poly(poly, self.prec))
+    }
+
+    /// The arctangent `integral(f'/(1 + f^2))`. The constant term must be
+    /// zero.
+    pub fn atan(&self) -> Result<Self, SeriesError> {
+        self.check_constant()?;
+        let f = &self.poly;
+        let n = self.prec;
+        if !f.coefficients()[0].is_zero() {
+            return Err(SeriesError::NonZeroConstant);
+        }
+        let poly = if n <= 1 {
+            poly_constant(f, f.base_ring().zero())
+        } else {
+            let mut q = f.mullow(f, n - 1);
+            q.add_assign(&poly_constant(f, f.base_ring().one()));
+            let h = f.derivative().mullow(&poly_inv_series(&q, n - 1)?, n - 1);
+            poly_integral(&h, n)?
+        };
+        Ok(self.parent().from_poly(poly, n))
+    }
+
+    /// The sine and cosine, computed together from the differential
+    /// equations `s' = f'c` and `c' = -f's`. The constant term must be zero.
+    pub fn sin_cos(&self) -> Result<(Self, Self), SeriesError> {
+        self.check_constant()?;
+        let f = &self.poly;
+        let n = self.prec;
+        if !f.coefficients()[0].is_zero() {
+            return Err(SeriesError::NonZeroConstant);
+        }
+
+        let ring = f.base_ring();
+        let df = f.derivative();
+        let mut s = vec![ring.zero()];
+        let mut c = vec![ring.one()];
+        for m in 1..n {
+            let mut sm = ring.zero();
+            let mut cm = ring.zero();
+            for (k, d) in df.coefficients().iter().enumerate().take(m) {
+                // d is the coefficient (k+1)*f_(k+1) of x^k in f'
+                let mut t = d.clone();
+                t.mul_assign(&c[m - 1 - k]);
+                sm.add_assign(&t);
+                let mut t = d.clone();
+                t.mul_assign(&s[m - 1 - k]);
+                cm.sub_assign(&t);
+            }
+            let inv = integer_inverse(ring, m)?;
+            sm.mul_assign(&inv);
+            cm.mul_assign(&inv);
+            s.push(sm);
+            c.push(cm);
+        }
+
+        let parent = self.parent();
+        let sin = parent.poly_ring().new(s);
+        let cos = parent.poly_ring().new(c);
+        Ok((parent.from_poly(sin, n), parent.from_poly(cos, n)))
+    }
+
+    /// The sine. The constant term must be zero.
+    #[inline]
+    pub fn sin(&self) -> Result<Self, SeriesError> {
+        Ok(self.sin_cos()?.0)
+    }
+
+    /// The cosine. The constant term must be zero.
+    #[inline]
+    pub fn cos(&self) -> Result<Self, SeriesError> {
+        Ok(self.sin_cos()?.1)
+    }
+}
+
+impl<T: InvertibleRing> InvertibleRing for GenericPowerSeriesRing<T> {
+    #[inline]
+    fn inverse(&self, a: &GenericPowerSeries<T>) -> Option<GenericPowerSeries<T>> {
+        a.inv().ok()
+    }
+}
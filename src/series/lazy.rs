@@ -0,0 +1,777 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+use crate::poly::{GenericPoly, GenericPolyRing};
+use crate::series::SeriesError;
+use crate::util::check_unital;
+
+///////////////////////////////////////////////////////////////////
+// GenericLazySeriesRing<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub(crate) struct GenericLazySeriesCtx<T> {
+    pub(crate) poly_ring: GenericPolyRing<T>,
+    pub(crate) prec: usize,
+}
+
+impl<T: Ring> GenericLazySeriesCtx<T> {
+    pub fn new<V: Into<String>>(base_ring: T, var: V, prec: usize) -> Self {
+        GenericLazySeriesCtx {
+            poly_ring: GenericPolyRing::init(&base_ring, var),
+            prec,
+        }
+    }
+}
+
+/// Ring of lazily evaluated power series. Coefficients are computed on
+/// demand and cached.
+///
+/// Equality and display only look at the first `precision()` coefficients,
+/// since equality of infinite series is not decidable in general.
+#[derive(Debug, Clone)]
+pub struct GenericLazySeriesRing<T> {
+    pub(crate) ctx: Rc<GenericLazySeriesCtx<T>>,
+}
+
+impl<T: Ring> GenericLazySeriesRing<T> {
+    /// Initialize a lazy power series ring which compares and displays ten
+    /// coefficients.
+    #[inline]
+    pub fn init<S: Into<String>>(base_ring: &T, var: S) -> Self {
+        Self::init_with_precision(base_ring, var, 10)
+    }
+
+    /// Initialize a lazy power series ring which compares and displays the
+    /// first `prec` coefficients. Panics if the base ring has no identity,
+    /// e.g. a non-square matrix space.
+    #[inline]
+    pub fn init_with_precision<S: Into<String>>(
+        base_ring: &T,
+        var: S,
+        prec: usize
+    ) -> Self {
+        check_unital(base_ring);
+        GenericLazySeriesRing {
+            ctx: Rc::new(GenericLazySeriesCtx::new(base_ring.clone(), var, prec))
+        }
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.poly_ring.base_ring()
+    }
+
+    #[inline]
+    pub fn poly_ring(&self) -> &GenericPolyRing<T> {
+        &self.ctx.poly_ring
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.poly_ring.var()
+    }
+
+    #[inline]
+    pub fn set_var<S: Into<String>>(&mut self, var: S) {
+        self.ctx.poly_ring.ctx.var.replace(var.into());
+    }
+
+    /// The number of coefficients used for equality and display.
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.ctx.prec
+    }
+
+    fn series(&self, op: LazyOp<T>) -> GenericLazySeries<T> {
+        GenericLazySeries {
+            ctx: Rc::clone(&self.ctx),
+            node: Rc::new(LazyNode {
+                cache: RefCell::new(Vec::new()),
+                busy: Cell::new(false),
+                op,
+            }),
+        }
+    }
+
+    /// The generator `x`.
+    #[inline]
+    pub fn gen(&self) -> GenericLazySeries<T> {
+        let mut poly = self.poly_ring().zero();
+        poly.set_coefficient(1, self.base_ring().one());
+        self.from_poly(poly)
+    }
+
+    /// The series with finitely many nonzero terms given by `poly`.
+    #[inline]
+    pub fn from_poly(&self, poly: GenericPoly<T>) -> GenericLazySeries<T> {
+        self.series(LazyOp::Poly(poly))
+    }
+
+    /// The series whose coefficient of `x^n` is `f(n)`.
+    #[inline]
+    pub fn from_fn<F>(&self, f: F) -> GenericLazySeries<T>
+    where
+        F: Fn(usize) -> Elem<T> + 'static
+    {
+        self.series(LazyOp::Fn(Box::new(f)))
+    }
+
+    /// A placeholder series to be given a value with `define`, allowing
+    /// recursive definitions like `F = 1 + x*F^2`.
+    ///
+    /// The definition refers back to the placeholder weakly, so a recursively
+    /// defined series is freed with its last handle. Keep a handle to the
+    /// placeholder, or to a series built from it after `define`, while the
+    /// definition is in use, and likewise for every placeholder of mutually
+    /// recursive definitions.
+    #[inline]
+    pub fn undefined(&self) -> GenericLazySeries<T> {
+        self.series(LazyOp::Undefined(RefCell::new(None)))
+    }
+}
+
+impl<T: Ring> NewElement<&GenericLazySeries<T>> for GenericLazySeriesRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericLazySeries<T>) -> GenericLazySeries<T> {
+        val.clone()
+    }
+}
+
+impl<T: Ring> NewElement<&GenericPoly<T>> for GenericLazySeriesRing<T> {
+    #[inline]
+    fn new(&self, val: &GenericPoly<T>) -> GenericLazySeries<T> {
+        self.from_poly(val.clone())
+    }
+}
+
+impl<T: Ring> NewElement<GenericPoly<T>> for GenericLazySeriesRing<T> {
+    #[inline]
+    fn new(&self, val: GenericPoly<T>) -> GenericLazySeries<T> {
+        self.from_poly(val)
+    }
+}
+
+impl<S, T, const CAP: usize> NewElement<[S; CAP]> for GenericLazySeriesRing<T>
+where
+    T: Ring + NewElement<S>,
+{
+    #[inline]
+    fn new(&self, coeffs: [S; CAP]) -> GenericLazySeries<T> {
+        self.from_poly(self.poly_ring().new(coeffs))
+    }
+}
+
+impl<S, T> NewElement<Vec<S>> for GenericLazySeriesRing<T>
+where
+    T: Ring<Element=S>
+{
+    #[inline]
+    fn new(&self, coeffs: Vec<S>) -> GenericLazySeries<T> {
+        self.from_poly(self.poly_ring().new(coeffs))
+    }
+}
+
+impl<T: Ring + fmt::Display> fmt::Display for GenericLazySeriesRing<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Lazy power series ring in {} over {}",
+            self.var(),
+            self.base_ring()
+        )
+    }
+}
+
+impl<T: Ring + PartialEq> Eq for GenericLazySeriesRing<T> {}
+
+impl<T: Ring + PartialEq> PartialEq for GenericLazySeriesRing<T> {
+    #[inline]
+    fn eq(&self, rhs: &GenericLazySeriesRing<T>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) ||
+            self.base_ring() == rhs.base_ring()
+    }
+}
+
+impl<T: Ring> Parent for GenericLazySeriesRing<T> {
+    type Element = GenericLazySeries<T>;
+}
+
+impl<T: Ring> Identity<Additive> for GenericLazySeriesRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericLazySeries<T> {
+        self.from_poly(self.poly_ring().zero())
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for GenericLazySeriesRing<T> {}
+
+impl<T: Ring> Associative<Additive> for GenericLazySeriesRing<T> {}
+
+impl<T: Ring> Commutative<Additive> for GenericLazySeriesRing<T> {}
+
+impl<T: Ring> Identity<Multiplicative> for GenericLazySeriesRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericLazySeries<T> {
+        self.from_poly(self.poly_ring().one())
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for GenericLazySeriesRing<T> {}
+
+impl<T: Ring> Commutative<Multiplicative> for GenericLazySeriesRing<T> {}
+
+impl<T: Ring> Distributive for GenericLazySeriesRing<T> {}
+
+///////////////////////////////////////////////////////////////////
+// GenericLazySeries<T>
+///////////////////////////////////////////////////////////////////
+
+enum LazyLink<T: Ring> {
+    Strong(Rc<LazyNode<T>>),
+    Weak(Weak<LazyNode<T>>),
+}
+
+// an operand of a lazy series, held weakly when it is the back reference
+// closing the cycle of a recursive definition
+pub(crate) struct LazyChild<T: Ring> {
+    ctx: Rc<GenericLazySeriesCtx<T>>,
+    link: RefCell<LazyLink<T>>,
+}
+
+impl<T: Ring> LazyChild<T> {
+    fn new(a: &GenericLazySeries<T>) -> Self {
+        LazyChild {
+            ctx: Rc::clone(&a.ctx),
+            link: RefCell::new(LazyLink::Strong(Rc::clone(&a.node))),
+        }
+    }
+
+    fn get(&self) -> GenericLazySeries<T> {
+        let node = match &*self.link.borrow() {
+            LazyLink::Strong(node) => Rc::clone(node),
+            LazyLink::Weak(node) => node.upgrade()
+                .expect("Placeholder of a recursive definition was dropped."),
+        };
+        GenericLazySeries {
+            ctx: Rc::clone(&self.ctx),
+            node,
+        }
+    }
+
+    #[inline]
+    fn coeff(&self, n: usize) -> Elem<T> {
+        self.get().coeff(n)
+    }
+}
+
+// how to compute the next coefficient of a lazy series
+pub(crate) enum LazyOp<T: Ring> {
+    Poly(GenericPoly<T>),
+    Fn(Box<dyn Fn(usize) -> Elem<T>>),
+    Neg(LazyChild<T>),
+    Add(LazyChild<T>, LazyChild<T>),
+    Sub(LazyChild<T>, LazyChild<T>),
+    Mul(LazyChild<T>, LazyChild<T>),
+    Scalar(Elem<T>, LazyChild<T>),
+    Shift(LazyChild<T>, usize),
+    // f(g) and the known coefficients of the powers of g
+    Compose(LazyChild<T>, LazyChild<T>, RefCell<Vec<Vec<Elem<T>>>>),
+    Undefined(RefCell<Option<LazyChild<T>>>),
+}
+
+impl<T: Ring> LazyOp<T> {
+    fn children(&self) -> Vec<&LazyChild<T>> {
+        match self {
+            LazyOp::Poly(_) | LazyOp::Fn(_) | LazyOp::Undefined(_) => Vec::new(),
+            LazyOp::Neg(a) | LazyOp::Scalar(_, a) | LazyOp::Shift(a, _) => vec![a],
+            LazyOp::Add(a, b)
+            | LazyOp::Sub(a, b)
+            | LazyOp::Mul(a, b)
+            | LazyOp::Compose(a, b, _) => vec![a, b],
+        }
+    }
+}
+
+pub(crate) struct LazyNode<T: Ring> {
+    pub(crate) cache: RefCell<Vec<Elem<T>>>,
+    pub(crate) busy: Cell<bool>,
+    pub(crate) op: LazyOp<T>,
+}
+
+/// A lazily evaluated power series.
+#[derive(Clone)]
+pub struct GenericLazySeries<T: Ring> {
+    pub(crate) ctx: Rc<GenericLazySeriesCtx<T>>,
+    pub(crate) node: Rc<LazyNode<T>>,
+}
+
+impl<T: Ring> fmt::Display for GenericLazySeries<T>
+where
+    <T as Ring>::Element: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let x = self.var();
+        let prec = self.ctx.prec;
+
+        let mut out = Vec::with_capacity(prec + 1);
+        for i in 0..prec {
+            let c = self.coeff(i);
+            if c.is_zero() {
+                continue;
+            }
+            if i == 0 {
+                out.push(format!("{}", c));
+            } else if i == 1 && c.is_one() {
+                out.push(format!("{}", x));
+            } else if i == 1 {
+                out.push(format!("{}*{}", c, x));
+            } else if c.is_one() {
+                out.push(format!("{}^{}", x, i));
+            } else {
+                out.push(format!("{}*{}^{}", c, x, i));
+            }
+        }
+        match prec {
+            0 => out.push("O(1)".to_string()),
+            1 => out.push(format!("O({})", x)),
+            n => out.push(format!("O({}^{})", x, n)),
+        }
+        write!(f, "{}", out.join(" + "))
+    }
+}
+
+impl<T: Ring> Eq for GenericLazySeries<T> {}
+
+/// Two lazy series are considered equal if their first `precision()`
+/// coefficients agree.
+impl<T: Ring> PartialEq for GenericLazySeries<T> {
+    fn eq(&self, rhs: &GenericLazySeries<T>) -> bool {
+        Rc::ptr_eq(&self.node, &rhs.node) ||
+            (0..self.ctx.prec).all(|i| self.coeff(i) == rhs.coeff(i))
+    }
+}
+
+impl<T: Ring> Element for GenericLazySeries<T> {
+    type Parent = GenericLazySeriesRing<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericLazySeriesRing<T> {
+        GenericLazySeriesRing {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: Ring> Operation<Additive> for GenericLazySeries<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for GenericLazySeries<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        (0..self.ctx.prec).all(|i| self.coeff(i).is_zero())
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for GenericLazySeries<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: Ring> Operation<Multiplicative> for GenericLazySeries<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for GenericLazySeries<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeff(0).is_one() && (1..self.ctx.prec).all(|i| self.coeff(i).is_zero())
+    }
+}
+
+impl<T: Ring> GenericLazySeries<T> {
+    #[inline]
+    pub(crate) fn with_op(&self, op: LazyOp<T>) -> Self {
+        self.parent().series(op)
+    }
+
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        self.ctx.poly_ring.base_ring()
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ctx.poly_ring.var()
+    }
+
+    /// The coefficient of `x^n`. All lower coefficients are computed and
+    /// cached first.
+    ///
+    /// Panics if the series is recursively defined in terms of a coefficient
+    /// which is not yet known, or used before it is defined.
+    pub fn coeff(&self, n: usize) -> Elem<T> {
+        loop {
+            let k = self.node.cache.borrow().len();
+            if k > n {
+                break;
+            }
+            if self.node.busy.replace(true) {
+                panic!("Coefficient {} of the lazy series depends on itself.", k);
+            }
+            let c = self.compute(k);
+            self.node.busy.set(false);
+            self.node.cache.borrow_mut().push(c);
+        }
+        self.node.cache.borrow()[n].clone()
+    }
+
+    /// The first `n` coefficients.
+    pub fn coefficients(&self, n: usize) -> Vec<Elem<T>> {
+        if n > 0 {
+            self.coeff(n - 1);
+        }
+        self.node.cache.borrow()[..n].to_vec()
+    }
+
+    /// The series truncated to a polynomial of length at most `n`.
+    pub fn to_poly(&self, n: usize) -> GenericPoly<T> {
+        let mut res = self.ctx.poly_ring.new(self.coefficients(n));
+        if res.len() == 0 {
+            res.coefficients_mut().push(self.base_ring().zero());
+        }
+        res.normalize();
+        res
+    }
+
+    /// Give a value to a series created with `undefined`. The value may
+    /// refer to the series itself, provided every coefficient only depends
+    /// on lower ones.
+    ///
+    /// Panics if the series was not created with `undefined` or has already
+    /// been defined.
+    pub fn define(&self, value: &GenericLazySeries<T>) {
+        match &self.node.op {
+            LazyOp::Undefined(def) => {
+                let mut def = def.borrow_mut();
+                if def.is_some() {
+                    panic!("Lazy series has already been defined.");
+                }
+                *def = Some(LazyChild::new(value));
+            },
+            _ => panic!("Only series created with `undefined` can be defined."),
+        }
+        self.weaken_back_references();
+    }
+
+    // make every strong reference to this placeholder reachable from it
+    // weak, so the definition does not keep it alive
+    fn weaken_back_references(&self) {
+        let target = &self.node;
+        let mut seen = HashSet::new();
+        let mut stack = vec![Rc::clone(target)];
+        seen.insert(Rc::as_ptr(target));
+
+        while let Some(node) = stack.pop() {
+            let def = match &node.op {
+                LazyOp::Undefined(def) => def.borrow(),
+                _ => {
+                    for child in node.op.children() {
+                        Self::visit(child, target, &mut seen, &mut stack);
+                    }
+                    continue;
+                },
+            };
+            if let Some(child) = def.as_ref() {
+                Self::visit(child, target, &mut seen, &mut stack);
+            }
+        }
+    }
+
+    fn visit(
+        child: &LazyChild<T>,
+        target: &Rc<LazyNode<T>>,
+        seen: &mut HashSet<*const LazyNode<T>>,
+        stack: &mut Vec<Rc<LazyNode<T>>>
+    ) {
+        let next = match &*child.link.borrow() {
+            LazyLink::Strong(node) => Rc::clone(node),
+            LazyLink::Weak(_) => return,
+        };
+        if Rc::ptr_eq(&next, target) {
+            *child.link.borrow_mut() = LazyLink::Weak(Rc::downgrade(target));
+        } else if seen.insert(Rc::as_ptr(&next)) {
+            stack.push(next);
+        }
+    }
+
+    /// Multiply by the scalar `c`.
+    #[inline]
+    pub fn scalar_mul(&self, c: &Elem<T>) -> Self {
+        self.with_op(LazyOp::Scalar(c.clone(), LazyChild::new(self)))
+    }
+
+    /// Multiply by `x^k`.
+    #[inline]
+    pub fn shift_left(&self, k: usize) -> Self {
+        self.with_op(LazyOp::Shift(LazyChild::new(self), k))
+    }
+
+    /// The composition `f(g)`. The constant term of `g` must be zero, which
+    /// is only checked when coefficients are computed, so `g` may be a
+    /// placeholder that is defined later.
+    ///
+    /// Panics when a coefficient is computed if `g` has a nonzero constant
+    /// term.
+    pub fn compose(&self, g: &GenericLazySeries<T>) -> Self {
+        let f = LazyChild::new(self);
+        let g = LazyChild::new(g);
+        self.with_op(LazyOp::Compose(f, g, RefCell::new(Vec::new())))
+    }
+
+    // coefficient k of a product, skipping a factor when its partner is zero
+    // so that products with x*F in recursive definitions are well-founded
+    fn mul_coeff(a: &Self, b: &Self, k: usize) -> Elem<T> {
+        let mut res = a.base_ring().zero();
+        for i in 0..=k {
+            let j = k - i;
+            let t = if i <= j {
+                let ai = a.coeff(i);
+                if ai.is_zero() {
+                    continue;
+                }
+                let mut t = ai;
+                t.mul_assign(&b.coeff(j));
+                t
+            } else {
+                let bj = b.coeff(j);
+                if bj.is_zero() {
+                    continue;
+                }
+                let mut t = a.coeff(i);
+                t.mul_assign(&bj);
+                t
+            };
+            res.add_assign(&t);
+        }
+        res
+    }
+
+    // compute coefficient k, assuming coefficients 0..k are cached
+    fn compute(&self, k: usize) -> Elem<T> {
+        match &self.node.op {
+            LazyOp::Poly(p) => {
+                match p.coefficient(k) {
+                    Some(c) => c.clone(),
+                    None => self.base_ring().zero(),
+                }
+            },
+            LazyOp::Fn(f) => f(k),
+            LazyOp::Neg(a) => {
+                let mut c = a.coeff(k);
+                c.neg_assign();
+                c
+            },
+            LazyOp::Add(a, b) => {
+                let mut c = a.coeff(k);
+                c.add_assign(&b.coeff(k));
+                c
+            },
+            LazyOp::Sub(a, b) => {
+                let mut c = a.coeff(k);
+                c.sub_assign(&b.coeff(k));
+                c
+            },
+            LazyOp::Mul(a, b) => Self::mul_coeff(&a.get(), &b.get(), k),
+            LazyOp::Scalar(s, a) => {
+                let mut c = s.clone();
+                c.mul_assign(&a.coeff(k));
+                c
+            },
+            LazyOp::Shift(a, s) => {
+                if k < *s {
+                    self.base_ring().zero()
+                } else {
+                    a.coeff(k - s)
+                }
+            },
+            LazyOp::Compose(f, g, powers) => {
+                let ring = self.base_ring();
+                let g = g.get();
+                if k == 0 && !g.coeff(0).is_zero() {
+                    panic!("{}", SeriesError::NonZeroConstant);
+                }
+
+                // extend the coefficients of g^0, ..., g^k to x^k, where
+                // g^k starts at x^k since g has no constant term, using
+                // [x^k] g^i = sum_j g_j*[x^(k-j)] g^(i-1)
+                let gs: Vec<Elem<T>> = (0..=k).map(|j| g.coeff(j)).collect();
+                let mut powers = powers.borrow_mut();
+                powers.push(vec![ring.zero(); k]);
+                for i in 0..=k {
+                    let c = if i == 0 {
+                        if k == 0 { ring.one() } else { ring.zero() }
+                    } else {
+                        let mut s = ring.zero();
+                        for (j, gj) in gs.iter().enumerate().skip(1) {
+                            if gj.is_zero() {
+                                continue;
+                            }
+                            let mut t = gj.clone();
+                            t.mul_assign(&powers[i - 1][k - j]);
+                            s.add_assign(&t);
+                        }
+                        s
+                    };
+                    powers[i].push(c);
+                }
+
+                // only g^0, ..., g^k contribute to x^k
+                let mut res = ring.zero();
+                for (i, p) in powers.iter().enumerate() {
+                    let fi = f.coeff(i);
+                    if fi.is_zero() {
+                        continue;
+                    }
+                    let mut t = fi;
+                    t.mul_assign(&p[k]);
+                    res.add_assign(&t);
+                }
+                res
+            },
+            LazyOp::Undefined(def) => {
+                let value = def.borrow().as_ref().map(|v| v.get());
+                match value {
+                    Some(v) => v.coeff(k),
+                    None => panic!("Lazy series used before it was defined."),
+                }
+            },
+        }
+    }
+}
+
+// Neg
+impl<T: Ring> NegAssign for GenericLazySeries<T> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        *self = self.with_op(LazyOp::Neg(LazyChild::new(self)));
+    }
+}
+
+impl<T: Ring> Neg for GenericLazySeries<T> {
+    type Output = GenericLazySeries<T>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: Ring> Neg for &GenericLazySeries<T> {
+    type Output = GenericLazySeries<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl<T: Ring> AddAssign<&GenericLazySeries<T>> for GenericLazySeries<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &GenericLazySeries<T>) {
+        *self = self.with_op(LazyOp::Add(LazyChild::new(self), LazyChild::new(rhs)));
+    }
+}
+
+impl<T: Ring> AddFrom<&GenericLazySeries<T>> for GenericLazySeries<T> {
+    #[inline]
+    fn add_from(&mut self, lhs: &GenericLazySeries<T>) {
+        *self = self.with_op(LazyOp::Add(LazyChild::new(lhs), LazyChild::new(self)));
+    }
+}
+
+forward_binop! {
+    GenericLazySeries<T: Ring>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+
+impl<T: Ring> SubAssign<&Self> for GenericLazySeries<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = self.with_op(LazyOp::Sub(LazyChild::new(self), LazyChild::new(rhs)));
+    }
+}
+
+impl<T: Ring> SubFrom<&Self> for GenericLazySeries<T> {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        *self = self.with_op(LazyOp::Sub(LazyChild::new(lhs), LazyChild::new(self)));
+    }
+}
+
+forward_binop! {
+    GenericLazySeries<T: Ring>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+
+impl<T: Ring> MulAssign<&Self> for GenericLazySeries<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = self.with_op(LazyOp::Mul(LazyChild::new(self), LazyChild::new(rhs)));
+    }
+}
+
+impl<T: Ring> MulFrom<&Self> for GenericLazySeries<T> {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        *self = self.with_op(LazyOp::Mul(LazyChild::new(lhs), LazyChild::new(self)));
+    }
+}
+
+forward_binop! {
+    GenericLazySeries<T: Ring>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
@@ -0,0 +1,137 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::series::{GenericPowerSeries, PrecisionModel};
+
+impl<T: Ring> GenericPowerSeries<T> {
+    // precision of the product of self and rhs
+    fn mul_precision(&self, rhs: &Self) -> usize {
+        let va = self.valuation();
+        let vb = rhs.valuation();
+        let prec = std::cmp::min(self.prec + vb, rhs.prec + va);
+        match self.ctx.model {
+            PrecisionModel::Absolute => std::cmp::min(prec, self.ctx.prec),
+            PrecisionModel::Relative => std::cmp::min(prec, va + vb + self.ctx.prec),
+        }
+    }
+}
+
+// Neg
+impl<T: Ring> NegAssign for GenericPowerSeries<T> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.poly.neg_assign();
+    }
+}
+
+impl<T: Ring> Neg for GenericPowerSeries<T> {
+    type Output = GenericPowerSeries<T>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: Ring> Neg for &GenericPowerSeries<T> {
+    type Output = GenericPowerSeries<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl<T: Ring> AddAssign<&GenericPowerSeries<T>> for GenericPowerSeries<T> {
+    fn add_assign(&mut self, rhs: &GenericPowerSeries<T>) {
+        self.prec = std::cmp::min(self.prec, rhs.prec);
+        self.poly.add_assign(&rhs.poly);
+        self.reduce();
+    }
+}
+
+impl<T: Ring> AddFrom<&GenericPowerSeries<T>> for GenericPowerSeries<T> {
+    fn add_from(&mut self, lhs: &GenericPowerSeries<T>) {
+        self.prec = std::cmp::min(self.prec, lhs.prec);
+        self.poly.add_from(&lhs.poly);
+        self.reduce();
+    }
+}
+
+forward_binop! {
+    GenericPowerSeries<T: Ring>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+
+impl<T: Ring> SubAssign<&Self> for GenericPowerSeries<T> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.prec = std::cmp::min(self.prec, rhs.prec);
+        self.poly.sub_assign(&rhs.poly);
+        self.reduce();
+    }
+}
+
+impl<T: Ring> SubFrom<&Self> for GenericPowerSeries<T> {
+    fn sub_from(&mut self, lhs: &Self) {
+        self.prec = std::cmp::min(self.prec, lhs.prec);
+        self.poly.sub_from(&lhs.poly);
+        self.reduce();
+    }
+}
+
+forward_binop! {
+    GenericPowerSeries<T: Ring>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+
+impl<T: Ring> MulAssign<&Self> for GenericPowerSeries<T> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        let prec = self.mul_precision(rhs);
+        self.poly = self.poly.mullow(&rhs.poly, prec);
+        self.prec = prec;
+        self.reduce();
+    }
+}
+
+impl<T: Ring> MulFrom<&Self> for GenericPowerSeries<T> {
+    fn mul_from(&mut self, lhs: &Self) {
+        let prec = lhs.mul_precision(self);
+        self.poly = lhs.poly.mullow(&self.poly, prec);
+        self.prec = prec;
+        self.reduce();
+    }
+}
+
+forward_binop! {
+    GenericPowerSeries<T: Ring>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
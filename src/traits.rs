@@ -0,0 +1,149 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Capabilities of base rings that generic algorithms dispatch on.
+
+
+use inertia_algebra::*;
+
+use crate::finite_field;
+use crate::mat;
+use crate::util::ring_pow;
+
+/// Rings in which units can be detected and inverted.
+pub trait InvertibleRing: Ring {
+    /// The multiplicative inverse of `a`, or `None` if `a` is not a unit.
+    fn inverse(&self, a: &Elem<Self>) -> Option<Elem<Self>>;
+
+    /// Whether `a` is a unit.
+    #[inline]
+    fn is_unit(&self, a: &Elem<Self>) -> bool {
+        self.inverse(a).is_some()
+    }
+}
+
+/// Integral domains in which exact quotients can be computed.
+pub trait ExactDivisionRing: Ring {
+    /// The quotient `a/b` if `b` divides `a`, otherwise `None`.
+    fn divexact(&self, a: &Elem<Self>, b: &Elem<Self>) -> Option<Elem<Self>>;
+}
+
+/// Rings in which every nonzero element is a unit.
+pub trait DivisionRing: InvertibleRing + ExactDivisionRing {}
+
+/// Integral domains with greatest common divisors.
+pub trait GcdRing: ExactDivisionRing {
+    /// A greatest common divisor of `a` and `b`.
+    fn gcd(&self, a: &Elem<Self>, b: &Elem<Self>) -> Elem<Self>;
+
+    /// A unit `u` such that `a/u` is the canonical representative of the
+    /// associates of `a`, e.g. the sign of an integer or the leading
+    /// coefficient of a polynomial over a field. Returns one for zero.
+    fn canonical_unit(&self, a: &Elem<Self>) -> Elem<Self>;
+}
+
+/// Finite fields, for algorithms that need the order or the Frobenius map,
+/// such as factorization of polynomials.
+pub trait FiniteField: DivisionRing {
+    /// The characteristic `p`.
+    fn characteristic(&self) -> u64;
+
+    /// The degree `n` over the prime field, so the order is `p^n`.
+    fn degree(&self) -> u32;
+
+    /// The element with index `i` in a fixed enumeration of the field, for
+    /// `i` less than the order, with zero first and one second.
+    fn element(&self, i: u128) -> Elem<Self>;
+
+    /// The number of elements, or `None` if it does not fit in a `u128`.
+    #[inline]
+    fn order(&self) -> Option<u128> {
+        (self.characteristic() as u128).checked_pow(self.degree())
+    }
+
+    /// The Frobenius automorphism `a^p`.
+    #[inline]
+    fn frobenius(&self, a: &Elem<Self>) -> Elem<Self> {
+        ring_pow(self, a, self.characteristic().into())
+    }
+
+    /// Whether `a` is a square. Panics if the order does not fit in a
+    /// `u128`.
+    #[inline]
+    fn is_square(&self, a: &Elem<Self>) -> bool {
+        finite_field::is_square(self, a)
+    }
+
+    /// A square root of `a`, or `None` if `a` is not a square. Panics if the
+    /// order does not fit in a `u128`.
+    #[inline]
+    fn sqrt(&self, a: &Elem<Self>) -> Option<Elem<Self>> {
+        finite_field::sqrt(self, a)
+    }
+
+    /// The multiplicative order of `a`, or `None` for zero. Panics if the
+    /// order of the field does not fit in a `u128`.
+    #[inline]
+    fn multiplicative_order(&self, a: &Elem<Self>) -> Option<u128> {
+        finite_field::multiplicative_order(self, a)
+    }
+}
+
+/// Euclidean domains, with a division that rounds to a nearest quotient.
+pub trait EuclideanRing: ExactDivisionRing {
+    /// The quotient and remainder `a = q*b + r` with `q` nearest to `a/b`,
+    /// e.g. `|r| <= |b|/2` for integers, or `None` if `b` is zero.
+    fn divrem(&self, a: &Elem<Self>, b: &Elem<Self>) -> Option<(Elem<Self>, Elem<Self>)>;
+}
+
+/// Rings with preferred algorithms for determinants and characteristic
+/// polynomials, which `GenericMat::det` and `GenericMat::charpoly` dispatch
+/// on. The defaults use Berkowitz's division free algorithm, valid over any
+/// commutative ring; fields should use `mat::det_gaussian` and
+/// `mat::charpoly_hessenberg`, and other integral domains `mat::det_bareiss`.
+///
+/// Integral domains also report themselves with `is_integral_domain`, so that
+/// constructions over them, such as polynomial rings, can use fraction-free
+/// elimination and fall back to Berkowitz otherwise.
+pub trait DetRing: Ring {
+    /// The determinant of the `n` by `n` matrix with row-major entries `a`.
+    #[inline]
+    fn det(&self, a: &[Elem<Self>], n: usize) -> Elem<Self> {
+        mat::det_berkowitz(self, a, n)
+    }
+
+    /// The coefficients of the characteristic polynomial of the `n` by `n`
+    /// matrix with row-major entries `a`, in increasing order of degree.
+    #[inline]
+    fn charpoly(&self, a: &[Elem<Self>], n: usize) -> Vec<Elem<Self>> {
+        mat::berkowitz(self, a, n)
+    }
+
+    /// Whether the ring is an integral domain with exact quotients given by
+    /// `domain_divexact`. Defaults to `false`, which is always safe.
+    #[inline]
+    fn is_integral_domain(&self) -> bool {
+        false
+    }
+
+    /// The quotient `a/b` if `b` divides `a`, only called when
+    /// `is_integral_domain` is `true`.
+    #[inline]
+    fn domain_divexact(&self, _a: &Elem<Self>, _b: &Elem<Self>) -> Option<Elem<Self>> {
+        None
+    }
+}
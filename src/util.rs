@@ -0,0 +1,241 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+/// The image of the integer `n` in `ring`, computed by double-and-add.
+pub(crate) fn ring_integer<T: Ring>(ring: &T, n: u64) -> Elem<T> {
+    let mut res = ring.zero();
+    let one = ring.one();
+    for i in (0..64 - n.leading_zeros()).rev() {
+        let t = res.clone();
+        res.add_assign(&t);
+        if (n >> i) & 1 == 1 {
+            res.add_assign(&one);
+        }
+    }
+    res
+}
+
+/// Check that `ring` has a multiplicative identity, so that structures built
+/// over it are rejected when constructed rather than in later operations.
+///
+/// Rings in this crate without an identity, i.e. non-square matrix spaces,
+/// panic in `one` with `MatError::NotSquare`, so this asks for the identity
+/// and discards it. Every constructor of a ring over a base ring calls it,
+/// including those which also build a polynomial ring over the same base.
+#[inline]
+pub(crate) fn check_unital<T: Ring>(ring: &T) {
+    ring.one();
+}
+
+/// The power `a^e` in `ring`, computed by repeated squaring.
+pub(crate) fn ring_pow<T: Ring + ?Sized>(ring: &T, a: &Elem<T>, e: u128) -> Elem<T> {
+    let mut res = ring.one();
+    for i in (0..128 - e.leading_zeros()).rev() {
+        let t = res.clone();
+        res.mul_assign(&t);
+        if (e >> i) & 1 == 1 {
+            res.mul_assign(a);
+        }
+    }
+    res
+}
+
+// a^e mod n for n > 0
+fn powmod_u64(a: u64, mut e: u64, n: u64) -> u64 {
+    let n = n as u128;
+    let mut a = a as u128 % n;
+    let mut res = 1 % n;
+    while e > 0 {
+        if e & 1 == 1 {
+            res = res * a % n;
+        }
+        a = a * a % n;
+        e >>= 1;
+    }
+    res as u64
+}
+
+/// Whether `n` is prime, by the Miller-Rabin test with a set of bases that
+/// is deterministic for all 64-bit integers.
+pub(crate) fn is_prime_u64(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for &p in BASES.iter() {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for &a in BASES.iter() {
+        let mut x = powmod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = ((x as u128) * (x as u128) % n as u128) as u64;
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+// a + b mod n for a, b < n
+fn addmod_u128(a: u128, b: u128, n: u128) -> u128 {
+    let (s, overflow) = a.overflowing_add(b);
+    if overflow || s >= n {
+        s.wrapping_sub(n)
+    } else {
+        s
+    }
+}
+
+// a*b mod n for a, b < n, by double-and-add
+fn mulmod_u128(a: u128, b: u128, n: u128) -> u128 {
+    if let (Ok(a), Ok(b)) = (u64::try_from(a), u64::try_from(b)) {
+        return (a as u128) * (b as u128) % n;
+    }
+    let mut res = 0;
+    for i in (0..128 - b.leading_zeros()).rev() {
+        res = addmod_u128(res, res, n);
+        if (b >> i) & 1 == 1 {
+            res = addmod_u128(res, a, n);
+        }
+    }
+    res
+}
+
+// a^e mod n for n > 0
+fn powmod_u128(a: u128, mut e: u128, n: u128) -> u128 {
+    let mut a = a % n;
+    let mut res = 1 % n;
+    while e > 0 {
+        if e & 1 == 1 {
+            res = mulmod_u128(res, a, n);
+        }
+        a = mulmod_u128(a, a, n);
+        e >>= 1;
+    }
+    res
+}
+
+/// Whether `n` is prime, by the Miller-Rabin test with the first twenty
+/// prime bases, which is deterministic for 64-bit integers and probabilistic
+/// above.
+pub(crate) fn is_prime_u128(n: u128) -> bool {
+    const BASES: [u128; 20] = [
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71
+    ];
+    if let Ok(n) = u64::try_from(n) {
+        return is_prime_u64(n);
+    }
+    for &p in BASES.iter() {
+        if n % p == 0 {
+            return false;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for &a in BASES.iter() {
+        let mut x = powmod_u128(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mulmod_u128(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+// a nontrivial factor of an odd composite n, by Pollard's rho method
+fn pollard_rho_u128(n: u128) -> u128 {
+    for c in 1.. {
+        let f = |x| addmod_u128(mulmod_u128(x, x, n), c, n);
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd_u128(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!()
+}
+
+/// The prime factorization of `n > 0`, by trial division by small primes
+/// and Pollard's rho method.
+pub(crate) fn factor_u128(mut n: u128) -> Vec<(u128, u32)> {
+    let mut primes = Vec::new();
+    let mut p = 2;
+    while p < 1 << 16 && p <= n / p {
+        while n % p == 0 {
+            n /= p;
+            primes.push(p);
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime_u128(m) {
+            primes.push(m);
+        } else {
+            let d = pollard_rho_u128(m);
+            stack.push(d);
+            stack.push(m/d);
+        }
+    }
+    primes.sort_unstable();
+
+    let mut res: Vec<(u128, u32)> = Vec::new();
+    for p in primes {
+        match res.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => res.push((p, 1)),
+        }
+    }
+    res
+}
@@ -0,0 +1,365 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::*;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::traits::{DetRing, InvertibleRing};
+
+mod ops;
+
+/// Primitive integer types that can hold residues and moduli of `Zmod`.
+pub trait ZmodInt: Copy + Eq + Hash + fmt::Debug + fmt::Display {
+    /// The value of a nonnegative integer.
+    fn to_u128(self) -> u128;
+
+    /// The integer with value `x`, which must be in range.
+    fn from_u128(x: u128) -> Self;
+}
+
+macro_rules! impl_zmod_int {
+    ($($t:ident)*) => ($(
+        impl ZmodInt for $t {
+            #[inline]
+            fn to_u128(self) -> u128 {
+                self as u128
+            }
+
+            #[inline]
+            fn from_u128(x: u128) -> Self {
+                x as $t
+            }
+        }
+    )*)
+}
+
+impl_zmod_int! { usize u8 u16 u32 u64 isize i8 i16 i32 i64 }
+
+///////////////////////////////////////////////////////////////////
+// Zmod<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct ZmodCtx<T> {
+    pub(crate) modulus: T,
+}
+
+/// The ring `Z/nZ` of integers modulo a runtime modulus `n`, with residues
+/// stored as primitive integers in `[0, n)`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Zmod<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<ZmodCtx<T>>,
+}
+
+impl<T: ZmodInt> Zmod<T> {
+    /// The integers modulo `n`. Panics if `n` is not positive.
+    #[inline]
+    pub fn init(n: T) -> Self {
+        // negative moduli of signed types convert to huge values
+        let m = n.to_u128();
+        assert!(m > 0 && m <= i128::MAX as u128, "Modulus must be positive.");
+        Zmod {
+            ctx: Rc::new(ZmodCtx { modulus: n })
+        }
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> T {
+        self.ctx.modulus
+    }
+
+    // the modulus as a u128
+    #[inline]
+    pub(crate) fn n(&self) -> u128 {
+        self.ctx.modulus.to_u128()
+    }
+
+    /// The residue class of the integer `x`.
+    pub fn from_i128(&self, x: i128) -> ZmodElem<T> {
+        let r = x.rem_euclid(self.n() as i128) as u128;
+        self.from_residue(r)
+    }
+
+    // the residue class of r in [0, n)
+    #[inline]
+    pub(crate) fn from_residue(&self, r: u128) -> ZmodElem<T> {
+        ZmodElem {
+            ctx: Rc::clone(&self.ctx),
+            val: T::from_u128(r),
+        }
+    }
+}
+
+impl<T: ZmodInt> NewElement<&ZmodElem<T>> for Zmod<T> {
+    #[inline]
+    fn new(&self, val: &ZmodElem<T>) -> ZmodElem<T> {
+        val.clone()
+    }
+}
+
+macro_rules! impl_new_element {
+    ($($t:ident)*) => ($(
+        impl<T: ZmodInt> NewElement<$t> for Zmod<T> {
+            #[inline]
+            fn new(&self, val: $t) -> ZmodElem<T> {
+                self.from_i128(val as i128)
+            }
+        }
+
+        impl<T: ZmodInt> NewElement<&$t> for Zmod<T> {
+            #[inline]
+            fn new(&self, val: &$t) -> ZmodElem<T> {
+                self.from_i128(*val as i128)
+            }
+        }
+    )*)
+}
+
+impl_new_element! { usize u8 u16 u32 u64 isize i8 i16 i32 i64 }
+
+impl<T: ZmodInt> fmt::Display for Zmod<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Integers modulo {}", self.modulus())
+    }
+}
+
+impl<T: ZmodInt> Eq for Zmod<T> {}
+
+impl<T: ZmodInt> PartialEq for Zmod<T> {
+    #[inline]
+    fn eq(&self, rhs: &Zmod<T>) -> bool {
+        Rc::ptr_eq(&self.ctx, &rhs.ctx) || self.modulus() == rhs.modulus()
+    }
+}
+
+impl<T: ZmodInt> Hash for Zmod<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.modulus().hash(state);
+    }
+}
+
+impl<T: ZmodInt> Parent for Zmod<T> {
+    type Element = ZmodElem<T>;
+}
+
+impl<T: ZmodInt> Identity<Additive> for Zmod<T> {
+    #[inline]
+    fn identity(&self) -> ZmodElem<T> {
+        self.from_residue(0)
+    }
+}
+
+impl<T: ZmodInt> Divisible<Additive> for Zmod<T> {}
+
+impl<T: ZmodInt> Associative<Additive> for Zmod<T> {}
+
+impl<T: ZmodInt> Commutative<Additive> for Zmod<T> {}
+
+impl<T: ZmodInt> Identity<Multiplicative> for Zmod<T> {
+    #[inline]
+    fn identity(&self) -> ZmodElem<T> {
+        self.from_residue(1 % self.n())
+    }
+}
+
+impl<T: ZmodInt> Associative<Multiplicative> for Zmod<T> {}
+
+impl<T: ZmodInt> Commutative<Multiplicative> for Zmod<T> {}
+
+impl<T: ZmodInt> Distributive for Zmod<T> {}
+
+impl<T: ZmodInt> DetRing for Zmod<T> {}
+
+impl<T: ZmodInt> InvertibleRing for Zmod<T> {
+    #[inline]
+    fn inverse(&self, a: &ZmodElem<T>) -> Option<ZmodElem<T>> {
+        a.inv()
+    }
+
+    #[inline]
+    fn is_unit(&self, a: &ZmodElem<T>) -> bool {
+        a.is_unit()
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// ZmodElem<T>
+///////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZmodElem<T> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "T: Serialize",
+            deserialize = "T: Deserialize<'de>",
+        ))
+    )]
+    pub(crate) ctx: Rc<ZmodCtx<T>>,
+    pub(crate) val: T,
+}
+
+impl<T: ZmodInt> fmt::Debug for ZmodElem<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} mod {:?}", self.val, self.ctx.modulus)
+    }
+}
+
+impl<T: ZmodInt> fmt::Display for ZmodElem<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
+
+impl<T: ZmodInt> Eq for ZmodElem<T> {}
+
+impl<T: ZmodInt> PartialEq for ZmodElem<T> {
+    #[inline]
+    fn eq(&self, rhs: &ZmodElem<T>) -> bool {
+        self.val == rhs.val
+    }
+}
+
+impl<T: ZmodInt> Hash for ZmodElem<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent().hash(state);
+        self.val.hash(state);
+    }
+}
+
+impl<T: ZmodInt> Element for ZmodElem<T> {
+    type Parent = Zmod<T>;
+
+    #[inline]
+    fn parent(&self) -> Zmod<T> {
+        Zmod {
+            ctx: Rc::clone(&self.ctx),
+        }
+    }
+}
+
+impl<T: ZmodInt> Operation<Additive> for ZmodElem<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self + right
+    }
+}
+
+impl<T: ZmodInt> IsIdentity<Additive> for ZmodElem<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.residue() == 0
+    }
+}
+
+impl<T: ZmodInt> TwoSidedInverse<Additive> for ZmodElem<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: ZmodInt> Operation<Multiplicative> for ZmodElem<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self * right
+    }
+}
+
+impl<T: ZmodInt> IsIdentity<Multiplicative> for ZmodElem<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.residue() == 1 % self.ctx.modulus.to_u128()
+    }
+}
+
+impl<T: ZmodInt> ZmodElem<T> {
+    // the residue as a u128
+    #[inline]
+    pub(crate) fn residue(&self) -> u128 {
+        self.val.to_u128()
+    }
+
+    /// The residue in `[0, n)`.
+    #[inline]
+    pub fn value(&self) -> T {
+        self.val
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> T {
+        self.ctx.modulus
+    }
+
+    /// The multiplicative inverse, or `None` if `self` is not a unit.
+    pub fn inv(&self) -> Option<Self> {
+        // extended Euclid on (a, n), tracking the coefficient of a
+        let n = self.parent().n() as i128;
+        let (mut r0, mut r1) = (n, self.residue() as i128);
+        let (mut s0, mut s1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q*r1);
+            (s0, s1) = (s1, s0 - q*s1);
+        }
+        if r0 != 1 {
+            return None;
+        }
+        Some(self.parent().from_i128(s0))
+    }
+
+    /// Whether `self` is a unit, i.e. coprime to the modulus.
+    pub fn is_unit(&self) -> bool {
+        let (mut a, mut b) = (self.parent().n(), self.residue());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a == 1
+    }
+
+    /// Raise to the power `e` by repeated squaring.
+    pub fn pow(&self, e: u64) -> Self {
+        let n = self.parent().n();
+        let mut res = 1 % n;
+        for i in (0..64 - e.leading_zeros()).rev() {
+            res = res * res % n;
+            if (e >> i) & 1 == 1 {
+                res = res * self.residue() % n;
+            }
+        }
+        self.parent().from_residue(res)
+    }
+}
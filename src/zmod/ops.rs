@@ -0,0 +1,125 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+use inertia_algebra::structures::*;
+use inertia_algebra::ops::*;
+
+use crate::zmod::{ZmodElem, ZmodInt};
+
+impl<T: ZmodInt> ZmodElem<T> {
+    // replace the residue by r mod n for r < n^2
+    #[inline]
+    fn set_residue(&mut self, r: u128) {
+        self.val = T::from_u128(r % self.ctx.modulus.to_u128());
+    }
+}
+
+// Neg
+impl<T: ZmodInt> NegAssign for ZmodElem<T> {
+    #[inline]
+    fn neg_assign(&mut self) {
+        let n = self.ctx.modulus.to_u128();
+        self.set_residue(n - self.residue());
+    }
+}
+
+impl<T: ZmodInt> Neg for ZmodElem<T> {
+    type Output = ZmodElem<T>;
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T: ZmodInt> Neg for &ZmodElem<T> {
+    type Output = ZmodElem<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let res = self.clone();
+        res.neg()
+    }
+}
+
+// Add
+impl<T: ZmodInt> AddAssign<&Self> for ZmodElem<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.set_residue(self.residue() + rhs.residue());
+    }
+}
+
+impl<T: ZmodInt> AddFrom<&Self> for ZmodElem<T> {
+    #[inline]
+    fn add_from(&mut self, lhs: &Self) {
+        self.set_residue(lhs.residue() + self.residue());
+    }
+}
+
+forward_binop! {
+    ZmodElem<T: ZmodInt>
+    Add, add
+    AddAssign, add_assign
+    AddFrom, add_from
+}
+
+// Sub
+impl<T: ZmodInt> SubAssign<&Self> for ZmodElem<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        let n = self.ctx.modulus.to_u128();
+        self.set_residue(self.residue() + n - rhs.residue());
+    }
+}
+
+impl<T: ZmodInt> SubFrom<&Self> for ZmodElem<T> {
+    #[inline]
+    fn sub_from(&mut self, lhs: &Self) {
+        let n = self.ctx.modulus.to_u128();
+        self.set_residue(lhs.residue() + n - self.residue());
+    }
+}
+
+forward_binop! {
+    ZmodElem<T: ZmodInt>
+    Sub, sub
+    SubAssign, sub_assign
+    SubFrom, sub_from
+}
+
+// Mul
+impl<T: ZmodInt> MulAssign<&Self> for ZmodElem<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.set_residue(self.residue() * rhs.residue());
+    }
+}
+
+impl<T: ZmodInt> MulFrom<&Self> for ZmodElem<T> {
+    #[inline]
+    fn mul_from(&mut self, lhs: &Self) {
+        self.set_residue(lhs.residue() * self.residue());
+    }
+}
+
+forward_binop! {
+    ZmodElem<T: ZmodInt>
+    Mul, mul
+    MulAssign, mul_assign
+    MulFrom, mul_from
+}
@@ -0,0 +1,63 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::mat::GenericMatSpace;
+use inertia_generic::poly::GenericPolyRing;
+use inertia_generic::prime_field::PrimeField;
+
+#[test]
+fn hessenberg_with_row_swaps() {
+    let fp = PrimeField::init(7);
+    let fx = GenericPolyRing::init(&fp, "x");
+    let m4 = GenericMatSpace::init(&fp, 4u64, 4u64);
+
+    // the entry below the diagonal in the first column is zero, so the
+    // reduction swaps rows and columns 1 and 2
+    let a = m4.new([[1i64, 2, 3, 4], [0, 5, 6, 1], [3, 1, 0, 2], [2, 0, 4, 6]]);
+    let p = a.charpoly_hessenberg(&fx);
+    assert!(p == a.charpoly_berkowitz(&fx));
+    assert!(p == fx.new([4i64, 4, 3, 2, 1]));
+
+    // the cycle (0 2 3 1), which needs a swap in every column
+    let b = m4.new([[0i64, 1, 0, 0], [0, 0, 0, 1], [1, 0, 0, 0], [0, 0, 1, 0]]);
+    let p = b.charpoly_hessenberg(&fx);
+    assert!(p == b.charpoly_berkowitz(&fx));
+    assert!(p == fx.new([-1i64, 0, 0, 0, 1]));
+
+    // a first column which is already reduced
+    let m3 = GenericMatSpace::init(&fp, 3u64, 3u64);
+    let c = m3.new([[2i64, 1, 0], [0, 3, 1], [0, 4, 5]]);
+    let p = c.charpoly_hessenberg(&fx);
+    assert!(p == c.charpoly_berkowitz(&fx));
+    assert!(p == fx.new([6i64, 6, 4, 1]));
+}
+
+#[test]
+fn charpoly_in_given_ring() {
+    let fp = PrimeField::init(7);
+    let ft = GenericPolyRing::init(&fp, "t");
+    let a = GenericMatSpace::init(&fp, 4u64, 4u64)
+        .new([[1i64, 2, 3, 4], [0, 5, 6, 1], [3, 1, 0, 2], [2, 0, 4, 6]]);
+
+    let p = a.charpoly_in(&ft);
+    assert!(p.parent() == ft);
+    assert_eq!(p.var(), "t");
+    assert!(p == ft.new([4i64, 4, 3, 2, 1]));
+    assert!(a.charpoly() == p);
+    assert_eq!(a.charpoly().var(), "x");
+}
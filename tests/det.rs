@@ -0,0 +1,83 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use inertia_algebra::*;
+use inertia_generic::integer::IntegerRing;
+use inertia_generic::mat::GenericMatSpace;
+use inertia_generic::poly::GenericPolyRing;
+use inertia_generic::quad::GenericQuadRing;
+use inertia_generic::traits::DetRing;
+use inertia_generic::zmod::Zmod;
+use std::ops::{Add, Mul, Sub};
+
+// the determinant of a 3 by 3 matrix by cofactor expansion
+fn det3<E>(a: &[E]) -> E
+where
+    for<'a> &'a E: Add<&'a E, Output = E> + Sub<&'a E, Output = E> + Mul<&'a E, Output = E>,
+{
+    let minor = |i: usize, j: usize, k: usize, l: usize| &(&a[i]*&a[j]) - &(&a[k]*&a[l]);
+    let t0 = &a[0]*&minor(4, 8, 5, 7);
+    let t1 = &a[1]*&minor(3, 8, 5, 6);
+    let t2 = &a[2]*&minor(3, 7, 4, 6);
+    &(&t0 - &t1) + &t2
+}
+
+#[test]
+fn det_over_integer_polynomials() {
+    let zz = IntegerRing::init();
+    let zx = GenericPolyRing::init(&zz, "x");
+    assert!(zx.is_integral_domain());
+    let entries = vec![
+        zx.new([2i64, 1]), zx.new([3i64]), zx.new([0i64, 0, 1]),
+        zx.new([-1i64, 4]), zx.new([5i64, -2]), zx.new([1i64]),
+        zx.new([7i64]), zx.new([0i64, 3]), zx.new([-6i64, 1, 1]),
+    ];
+    let m = GenericMatSpace::init(&zx, 3u64, 3u64).from_entries(entries.clone());
+    assert!(m.det() == det3(&entries));
+}
+
+#[test]
+fn det_over_zmod_polynomials() {
+    // Z/6Z has zero divisors, so Bareiss elimination would divide by 2
+    let r = Zmod::init(6u64);
+    let rx = GenericPolyRing::init(&r, "x");
+    assert!(!rx.is_integral_domain());
+    let entries = vec![
+        rx.new([2i64]), rx.new([3i64, 1]), rx.new([1i64]),
+        rx.new([4i64, 1]), rx.new([2i64]), rx.new([0i64, 5]),
+        rx.new([3i64]), rx.new([1i64, 1]), rx.new([2i64, 0, 1]),
+    ];
+    let m = GenericMatSpace::init(&rx, 3u64, 3u64).from_entries(entries.clone());
+    assert!(m.det() == det3(&entries));
+}
+
+#[test]
+fn det_over_polynomials_with_split_quadratic_base() {
+    // s^2 = 4 makes (s - 2)*(s + 2) = 0
+    let zz = IntegerRing::init();
+    let k = GenericQuadRing::init(&zz, &zz.new(4i64));
+    let kx = GenericPolyRing::init(&k, "x");
+    assert!(!kx.is_integral_domain());
+    let c = |a: i64, b: i64| kx.new([&k.new((a, b))]);
+    let x = kx.new([&k.zero(), &k.one()]);
+    let entries = vec![
+        c(-2, 1), c(1, 0), c(0, 0),
+        x.clone(), c(2, 1), c(1, 0),
+        c(1, 0), c(3, -1), &x + &c(0, 1),
+    ];
+    let m = GenericMatSpace::init(&kx, 3u64, 3u64).from_entries(entries.clone());
+    assert!(m.det() == det3(&entries));
+}
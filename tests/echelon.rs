@@ -0,0 +1,102 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::mat::GenericMatSpace;
+use inertia_generic::prime_field::PrimeField;
+
+#[test]
+fn rref_of_rank_deficient_matrix() {
+    let fp = PrimeField::init(7);
+    let s34 = GenericMatSpace::init(&fp, 3u64, 4u64);
+    // the last row is the sum of the first two
+    let a = s34.new([[1i64, 2, 0, 3], [2, 4, 1, 1], [3, 6, 1, 4]]);
+
+    let r = a.rref();
+    assert_eq!(r.pivots, vec![0, 2]);
+    assert_eq!(r.rank(), 2);
+    assert_eq!(a.rank(), 2);
+    assert!(r.form == s34.new([[1i64, 2, 0, 3], [0, 0, 1, 2], [0, 0, 0, 0]]));
+    assert!(&r.transform*&a == r.form);
+    assert_eq!(r.transform.rank(), 3);
+}
+
+#[test]
+fn subspaces_of_wide_matrix() {
+    let fp = PrimeField::init(7);
+    let a = GenericMatSpace::init(&fp, 3u64, 4u64)
+        .new([[1i64, 2, 0, 3], [2, 4, 1, 1], [3, 6, 1, 4]]);
+
+    let k = a.nullspace();
+    assert_eq!((k.nrows(), k.ncols()), (4, 2));
+    assert!(k == GenericMatSpace::init(&fp, 4u64, 2u64)
+        .new([[-2i64, -3], [1, 0], [0, -2], [0, 1]]));
+    assert!(&a*&k == GenericMatSpace::init(&fp, 3u64, 2u64).zero());
+
+    let c = a.column_space();
+    assert!(c == GenericMatSpace::init(&fp, 3u64, 2u64).new([[1i64, 0], [2, 1], [3, 1]]));
+    assert_eq!(c.rank(), 2);
+
+    let r = a.row_space();
+    assert!(r == GenericMatSpace::init(&fp, 2u64, 4u64)
+        .new([[1i64, 2, 0, 3], [0, 0, 1, 2]]));
+    assert_eq!(r.rank(), 2);
+}
+
+#[test]
+fn subspaces_of_tall_matrix() {
+    let fp = PrimeField::init(7);
+    let a = GenericMatSpace::init(&fp, 4u64, 3u64)
+        .new([[1i64, 2, 3], [2, 4, 6], [0, 1, 1], [3, 1, 4]]);
+
+    let r = a.rref();
+    assert_eq!(r.pivots, vec![0, 1]);
+    assert!(&r.transform*&a == r.form);
+    assert_eq!(r.transform.rank(), 4);
+
+    // the third column is the sum of the first two
+    let k = a.nullspace();
+    assert!(k == GenericMatSpace::init(&fp, 3u64, 1u64).new([[-1i64], [-1], [1]]));
+    assert!(&a*&k == GenericMatSpace::init(&fp, 4u64, 1u64).zero());
+
+    let c = a.column_space();
+    assert_eq!((c.nrows(), c.ncols()), (4, 2));
+    assert!(c == GenericMatSpace::init(&fp, 4u64, 2u64)
+        .new([[1i64, 2], [2, 4], [0, 1], [3, 1]]));
+    assert_eq!(a.row_space().nrows(), 2);
+}
+
+#[test]
+fn subspaces_of_zero_and_invertible_matrices() {
+    let fp = PrimeField::init(7);
+    let s33 = GenericMatSpace::init(&fp, 3u64, 3u64);
+
+    let z = s33.zero();
+    assert_eq!(z.rank(), 0);
+    assert!(z.rref().pivots.is_empty());
+    assert!(z.nullspace() == s33.one());
+    assert_eq!(z.column_space().ncols(), 0);
+    assert_eq!(z.row_space().nrows(), 0);
+
+    let a = s33.new([[2i64, 1, 0], [0, 3, 1], [1, 0, 5]]);
+    let r = a.rref();
+    assert_eq!(r.pivots, vec![0, 1, 2]);
+    assert!(r.form == s33.one());
+    assert!(&r.transform*&a == s33.one());
+    assert_eq!(a.nullspace().ncols(), 0);
+    assert!(a.column_space() == a);
+}
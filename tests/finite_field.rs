@@ -0,0 +1,68 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use inertia_algebra::*;
+use inertia_generic::finite_field::GenericFiniteField;
+use inertia_generic::poly::GenericPolyRing;
+use inertia_generic::prime_field::PrimeField;
+use inertia_generic::traits::FiniteField;
+
+// the field with p^2 elements for the Mersenne prime p = 2^61 - 1, whose
+// order does not fit in a u64
+fn large_field() -> GenericFiniteField<PrimeField> {
+    let fp = PrimeField::init((1 << 61) - 1);
+    let fpx = GenericPolyRing::init(&fp, "x");
+    GenericFiniteField::init(&fpx.new([1i64, 0, 1]))
+}
+
+#[test]
+fn large_field_order() {
+    let k = large_field();
+    let p = (1u128 << 61) - 1;
+    assert_eq!(k.order(), Some(p*p));
+
+    // x^2 = -1, and p^2 - 1 = 2^62*(2^60 - 1) has only small odd factors
+    let x = k.gen();
+    assert_eq!(x.multiplicative_order(), Some(4));
+    assert_eq!(k.new([-1i64, 0]).multiplicative_order(), Some(2));
+    let g = k.new([3i64, 1]);
+    assert!((p*p - 1) % g.multiplicative_order().unwrap() == 0);
+}
+
+#[test]
+fn large_field_sqrt() {
+    let k = large_field();
+    let x = k.gen();
+    assert!(x.is_square());
+    let r = x.sqrt().unwrap();
+    assert!(&r*&r == x);
+
+    let a = k.new([3i64, 1]);
+    let b = &a*&a;
+    let r = b.sqrt().unwrap();
+    assert!(r == a || r == -&a);
+}
+
+#[test]
+fn large_field_element() {
+    let k = large_field();
+    let p = (1u128 << 61) - 1;
+    assert!(k.element(0).is_zero());
+    assert!(k.element(1).is_one());
+    assert!(k.element(p) == k.gen());
+    assert!(k.element(p + 1) == k.new([1i64, 1]));
+    assert!(k.element(p*p - 1) == k.new([-1i64, -1]));
+}
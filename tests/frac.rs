@@ -0,0 +1,52 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::frac::GenericFracField;
+use inertia_generic::integer::IntegerRing;
+use inertia_generic::poly::GenericPolyRing;
+
+#[test]
+fn rationals_are_reduced() {
+    let zz = IntegerRing::init();
+    let qq = GenericFracField::init(&zz);
+
+    let a = qq.new((6i64, -4i64));
+    assert!(a.numerator() == &zz.new(-3i64));
+    assert!(a.denominator() == &zz.new(2i64));
+
+    let b = qq.new((5i64, 6i64));
+    let c = &a + &b;
+    assert!(c.numerator() == &zz.new(-2i64));
+    assert!(c.denominator() == &zz.new(3i64));
+    assert!(&c*&c.inv().unwrap() == qq.one());
+}
+
+#[test]
+fn rational_functions_are_reduced() {
+    let zz = IntegerRing::init();
+    let qq = GenericFracField::init(&zz);
+    let qx = GenericPolyRing::init(&qq, "x");
+    let k = GenericFracField::init(&qx);
+
+    // (x^2 - 1)/(2*x + 2) = (x - 1)/2
+    let num = qx.new(vec![qq.new((-1i64, 1i64)), qq.zero(), qq.one()]);
+    let den = qx.new(vec![qq.new((2i64, 1i64)), qq.new((2i64, 1i64))]);
+    let f = k.from_parts(num, den).unwrap();
+    assert!(f.denominator() == &qx.one());
+    assert!(f.numerator() == &qx.new(vec![qq.new((-1i64, 2i64)), qq.new((1i64, 2i64))]));
+}
@@ -0,0 +1,114 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use inertia_algebra::*;
+use inertia_generic::mat::{GenericMat, GenericMatSpace};
+use inertia_generic::poly::{GenericPoly, GenericPolyRing};
+use inertia_generic::prime_field::PrimeField;
+use std::cmp::Ordering;
+
+// the n by n matrix with entries f(i, j)
+fn mat_from_fn(
+    fp: &PrimeField,
+    n: usize,
+    f: impl Fn(usize, usize) -> i64
+) -> GenericMat<PrimeField> {
+    let entries = (0..n*n).map(|k| fp.new(f(k / n, k % n))).collect();
+    GenericMatSpace::init(fp, n as u64, n as u64).from_entries(entries)
+}
+
+// the block diagonal matrix of Jordan blocks J_k(lambda) for the sizes k
+fn jordan(fp: &PrimeField, lambda: i64, sizes: &[usize]) -> GenericMat<PrimeField> {
+    let n = sizes.iter().sum();
+    let mut ends = Vec::new();
+    let mut k = 0;
+    for s in sizes {
+        k += s;
+        ends.push(k);
+    }
+    mat_from_fn(fp, n, |i, j| {
+        if i == j {
+            lambda
+        } else if j == i + 1 && !ends.contains(&j) {
+            1
+        } else {
+            0
+        }
+    })
+}
+
+// S*a*S^-1 for an invertible S = L*U with unit triangular L and U
+fn conjugate(fp: &PrimeField, a: &GenericMat<PrimeField>) -> GenericMat<PrimeField> {
+    let n = a.nrows();
+    let l = mat_from_fn(fp, n, |i, j| match i.cmp(&j) {
+        Ordering::Equal => 1,
+        Ordering::Greater => (i + 2*j + 1) as i64,
+        Ordering::Less => 0,
+    });
+    let u = mat_from_fn(fp, n, |i, j| match i.cmp(&j) {
+        Ordering::Equal => 1,
+        Ordering::Less => (3*i + j + 2) as i64,
+        Ordering::Greater => 0,
+    });
+    let s = &l*&u;
+    &(&s*a)*&s.inverse().unwrap()
+}
+
+// check the Frobenius form of a and return its invariant factors
+fn check_frobenius(a: &GenericMat<PrimeField>) -> Vec<GenericPoly<PrimeField>> {
+    let ff = a.frobenius_form();
+    let p = &ff.transform;
+    assert!(&(&p.inverse().unwrap()*a)*p == ff.form);
+
+    let factors = ff.invariant_factors;
+    for w in factors.windows(2) {
+        assert!(w[1].divexact(&w[0]).is_some());
+    }
+    assert!(factors.last().unwrap() == &a.minpoly());
+    let r = factors[0].parent();
+    assert!(factors.iter().fold(r.one(), |s, f| &s*f) == a.charpoly());
+    factors
+}
+
+#[test]
+fn frobenius_of_repeated_jordan_blocks() {
+    let fp = PrimeField::init(101);
+    let r = GenericPolyRing::init(&fp, "x");
+    let lambda = 3;
+    let a = conjugate(&fp, &jordan(&fp, lambda, &[2, 2, 1]));
+
+    let factors = check_frobenius(&a);
+    let f = r.new([-lambda, 1]);
+    let f2 = &f*&f;
+    assert!(factors.len() == 3);
+    assert!(factors[0] == f);
+    assert!(factors[1] == f2);
+    assert!(factors[2] == f2);
+}
+
+#[test]
+fn frobenius_of_derogatory_nilpotent() {
+    let fp = PrimeField::init(101);
+    let a = conjugate(&fp, &jordan(&fp, 0, &[3, 2, 1]));
+
+    let factors = check_frobenius(&a);
+    let degrees: Vec<usize> = factors.iter().map(|f| f.deg().unwrap()).collect();
+    assert_eq!(degrees, vec![1, 2, 3]);
+    for f in factors.iter() {
+        assert!(f.coefficients().iter().rev().skip(1).all(|c| c.is_zero()));
+    }
+    assert!(a.minpoly().deg() == Some(3));
+}
@@ -0,0 +1,186 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use inertia_algebra::*;
+use inertia_generic::integer::{Integer, IntegerRing};
+use inertia_generic::mat::{GenericMat, GenericMatSpace, MatError};
+use inertia_generic::prime_field::PrimeField;
+
+// matrices given by their rows, each needing row swaps during elimination
+const SINGULAR: [[i64; 4]; 4] = [
+    [0, 0, 1, 2],
+    [0, 3, 1, 0],
+    [2, 1, 0, 1],
+    [2, 4, 1, 1],
+];
+const WIDE: [[i64; 5]; 3] = [
+    [0, 0, 2, 1, 5],
+    [0, 4, 1, 0, 3],
+    [0, 8, 2, 0, 6],
+];
+const TALL: [[i64; 3]; 5] = [
+    [0, 1, 2],
+    [0, 2, 4],
+    [3, 1, 1],
+    [6, 3, 4],
+    [0, 0, 0],
+];
+const ZERO: [[i64; 4]; 3] = [[0; 4]; 3];
+
+fn mat_fp<const R: usize, const C: usize>(
+    fp: &PrimeField,
+    rows: [[i64; C]; R]
+) -> GenericMat<PrimeField> {
+    GenericMatSpace::init(fp, R as u64, C as u64).new(rows)
+}
+
+fn mat_zz<const R: usize, const C: usize>(
+    zz: &IntegerRing,
+    rows: [[i64; C]; R]
+) -> GenericMat<IntegerRing> {
+    GenericMatSpace::init(zz, R as u64, C as u64).new(rows)
+}
+
+#[test]
+fn plu_reconstructs() {
+    let fp = PrimeField::init(101);
+    let cases = [
+        (mat_fp(&fp, SINGULAR), 3),
+        (mat_fp(&fp, WIDE), 2),
+        (mat_fp(&fp, TALL), 2),
+        (mat_fp(&fp, ZERO), 0),
+    ];
+    for (a, rank) in cases.iter() {
+        let lu = a.plu();
+        assert_eq!(lu.rank(), *rank);
+        assert!(lu.reconstruct() == *a);
+        assert!(&lu.permutation_matrix()*&(&lu.l*&lu.u) == *a);
+    }
+}
+
+#[test]
+fn fraction_free_lu_reconstructs() {
+    let zz = IntegerRing::init();
+    let cases = [
+        (mat_zz(&zz, SINGULAR), 3),
+        (mat_zz(&zz, WIDE), 2),
+        (mat_zz(&zz, TALL), 2),
+        (mat_zz(&zz, ZERO), 0),
+    ];
+    for (a, rank) in cases.iter() {
+        let lu = a.fraction_free_lu();
+        assert_eq!(lu.rank(), *rank);
+        assert!(lu.reconstruct() == *a);
+    }
+}
+
+#[test]
+fn plu_solve_detects_inconsistent_systems() {
+    let fp = PrimeField::init(101);
+    let a = mat_fp(&fp, [[1, 2, 3], [2, 4, 6], [1, 1, 1]]);
+    let lu = a.plu();
+
+    let b = mat_fp(&fp, [[1], [3], [1]]);
+    assert!(matches!(lu.solve(&b), Err(MatError::Inconsistent)));
+
+    let b = mat_fp(&fp, [[1, 0], [2, 0], [1, 5]]);
+    let x = lu.solve(&b).unwrap();
+    assert!(&a*&x == b);
+
+    let b = mat_fp(&fp, [[1], [2]]);
+    assert!(matches!(lu.solve(&b), Err(MatError::DimensionMismatch { .. })));
+}
+
+#[test]
+fn plu_ldu_reconstructs() {
+    let fp = PrimeField::init(101);
+    let cases = [
+        mat_fp(&fp, SINGULAR),
+        mat_fp(&fp, WIDE),
+        mat_fp(&fp, TALL),
+        mat_fp(&fp, ZERO),
+    ];
+    for a in cases.iter() {
+        let lu = a.plu();
+        let (l, d, u) = lu.ldu();
+        assert!(&lu.permutation_matrix()*&(&(&l*&d)*&u) == *a);
+        for (i, &p) in lu.pivots.iter().enumerate() {
+            assert!(u.entry(i, p).is_one());
+            assert!(d.entry(i, i) == lu.u.entry(i, p));
+        }
+    }
+}
+
+// the m by m scalar matrix c*I
+fn scalar_zz(zz: &IntegerRing, m: usize, c: &Integer) -> GenericMat<IntegerRing> {
+    let mut res = GenericMatSpace::init(zz, m as u64, m as u64).one();
+    for i in 0..m {
+        *res.entry_mut(i, i) = c.clone();
+    }
+    res
+}
+
+#[test]
+fn fraction_free_lu_solves() {
+    let zz = IntegerRing::init();
+    let a = mat_zz(&zz, [[2, 1, 1], [1, 3, 2], [1, 0, 4]]);
+    let lu = a.fraction_free_lu();
+    assert!(lu.diagonal_matrix() == mat_zz(&zz, [[2, 0, 0], [0, 10, 0], [0, 0, 95]]));
+
+    // the determinant is 19 and the solution the first column of the adjugate
+    let b = mat_zz(&zz, [[1], [0], [0]]);
+    let (x, den) = lu.solve(&b).unwrap();
+    assert!(den == zz.new(19i64));
+    assert!(x == mat_zz(&zz, [[12], [-2], [-3]]));
+
+    // the same factorization for several right-hand sides
+    let b = mat_zz(&zz, [[1, 5], [2, -1], [3, 0]]);
+    let (x, den) = lu.solve(&b).unwrap();
+    assert!(&a*&x == &scalar_zz(&zz, 3, &den)*&b);
+
+    let b = mat_zz(&zz, [[1], [2]]);
+    assert!(matches!(lu.solve(&b), Err(MatError::DimensionMismatch { .. })));
+}
+
+#[test]
+fn fraction_free_lu_solves_singular_systems() {
+    let zz = IntegerRing::init();
+
+    // the last row of SINGULAR is the sum of the two before
+    let a = mat_zz(&zz, SINGULAR);
+    let lu = a.fraction_free_lu();
+    let b = mat_zz(&zz, [[3], [4], [4], [8]]);
+    let (x, den) = lu.solve(&b).unwrap();
+    assert!(&a*&x == &scalar_zz(&zz, 4, &den)*&b);
+    let b = mat_zz(&zz, [[0], [0], [0], [1]]);
+    assert!(matches!(lu.solve(&b), Err(MatError::Inconsistent)));
+
+    // the last row of WIDE is twice the one before
+    let a = mat_zz(&zz, WIDE);
+    let lu = a.fraction_free_lu();
+    let b = mat_zz(&zz, [[1, 0], [2, 3], [4, 6]]);
+    let (x, den) = lu.solve(&b).unwrap();
+    assert!(&a*&x == &scalar_zz(&zz, 3, &den)*&b);
+    for j in [0, 3, 4] {
+        assert!(x.entry(j, 0).is_zero() && x.entry(j, 1).is_zero());
+    }
+    let b = mat_zz(&zz, [[0], [1], [1]]);
+    assert!(matches!(lu.solve(&b), Err(MatError::Inconsistent)));
+
+    let a = mat_zz(&zz, ZERO);
+    let (x, den) = a.fraction_free_lu().solve(&mat_zz(&zz, [[0], [0], [0]])).unwrap();
+    assert!(den.is_one() && x.entry(0, 0).is_zero());
+}
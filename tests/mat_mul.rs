@@ -0,0 +1,78 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use inertia_algebra::*;
+use inertia_generic::integer::IntegerRing;
+use inertia_generic::mat::{GenericMat, GenericMatSpace};
+
+// a matrix with pseudorandom entries in [-50, 50]
+fn random_mat(
+    space: &GenericMatSpace<IntegerRing>,
+    seed: &mut u64
+) -> GenericMat<IntegerRing> {
+    let zz = space.base_ring();
+    let entries = (0..space.nrows()*space.ncols()).map(|_| {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        zz.new((*seed % 101) as i64 - 50)
+    }).collect();
+    space.from_entries(entries)
+}
+
+const SHAPES: [(usize, usize, usize); 7] = [
+    (5, 7, 3),
+    (7, 5, 9),
+    (9, 9, 9),
+    (3, 11, 2),
+    (13, 1, 6),
+    (6, 10, 14),
+    (17, 15, 16),
+];
+
+#[test]
+fn strassen_matches_classical() {
+    let zz = IntegerRing::init();
+    let mut seed = 0x2545_f491_4f6c_dd1d;
+    for (m, n, p) in SHAPES {
+        let a = random_mat(&GenericMatSpace::init(&zz, m as u64, n as u64), &mut seed);
+        let b = random_mat(&GenericMatSpace::init(&zz, n as u64, p as u64), &mut seed);
+        let c = a.mul_classical(&b);
+        assert_eq!((c.nrows(), c.ncols()), (m, p));
+        for threshold in 1..=3 {
+            for bs in [1, 2, 5] {
+                assert!(a.mul_strassen(&b, threshold, bs) == c);
+            }
+        }
+        assert!(a.mul_blocked(&b, 3) == c);
+    }
+}
+
+#[test]
+fn tuning_is_shared_by_the_space() {
+    let zz = IntegerRing::init();
+    let mut seed = 0x9e37_79b9_7f4a_7c15;
+    let space = GenericMatSpace::init(&zz, 9u64, 9u64);
+    let a = random_mat(&space, &mut seed);
+    let b = random_mat(&space, &mut seed);
+
+    space.clone().set_strassen_threshold(2);
+    a.parent().set_block_size(3);
+    assert_eq!(space.strassen_threshold(), 2);
+    assert_eq!(b.parent().block_size(), 3);
+    assert!(a.mul_best(&b) == a.mul_classical(&b));
+    assert!(&a*&b == a.mul_classical(&b));
+}
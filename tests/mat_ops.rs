@@ -0,0 +1,96 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::integer::IntegerRing;
+use inertia_generic::mat::{GenericMatSpace, MatError};
+
+#[test]
+fn elementwise_arithmetic() {
+    let zz = IntegerRing::init();
+    let space = GenericMatSpace::init(&zz, 2u64, 3u64);
+    let a = space.new([[1i64, -2, 3], [0, 5, -6]]);
+    let b = space.new([[4i64, 4, -1], [2, -5, 7]]);
+
+    assert!(&a + &b == space.new([[5i64, 2, 2], [2, 0, 1]]));
+    assert!(&a - &b == space.new([[-3i64, -6, 4], [-2, 10, -13]]));
+    assert!(-&a == space.new([[-1i64, 2, -3], [0, -5, 6]]));
+    assert!(&a - &a == space.zero());
+    assert!(&(&a + &b) - &b == a);
+
+    let mut c = a.clone();
+    *c.entry_mut(1, 2) = zz.new(8i64);
+    assert!(c.entry(1, 2) == &zz.new(8i64));
+    assert!(c.entry(0, 1) == a.entry(0, 1));
+    assert!(c != a);
+}
+
+#[test]
+#[should_panic(expected = "incompatible matrix dimensions 2x3 and 3x2")]
+fn add_panics_on_dimension_mismatch() {
+    let zz = IntegerRing::init();
+    let a = GenericMatSpace::init(&zz, 2u64, 3u64).zero();
+    let b = GenericMatSpace::init(&zz, 3u64, 2u64).zero();
+    let _ = &a + &b;
+}
+
+#[test]
+#[should_panic(expected = "incompatible matrix dimensions 2x3 and 2x2")]
+fn sub_panics_on_dimension_mismatch() {
+    let zz = IntegerRing::init();
+    let a = GenericMatSpace::init(&zz, 2u64, 3u64).zero();
+    let b = GenericMatSpace::init(&zz, 2u64, 2u64).zero();
+    let _ = &a - &b;
+}
+
+#[test]
+fn try_mul_rejects_incompatible_dimensions() {
+    let zz = IntegerRing::init();
+    let a = GenericMatSpace::init(&zz, 2u64, 3u64).zero();
+    let b = GenericMatSpace::init(&zz, 2u64, 4u64).zero();
+    assert_eq!(
+        a.try_mul(&b).err(),
+        Some(MatError::DimensionMismatch { lhs: (2, 3), rhs: (2, 4) })
+    );
+    assert!(b.try_mul(&a).is_err());
+}
+
+#[test]
+fn product_lands_in_new_space() {
+    let zz = IntegerRing::init();
+    let s23 = GenericMatSpace::init(&zz, 2u64, 3u64);
+    let s34 = GenericMatSpace::init(&zz, 3u64, 4u64);
+    s23.set_block_size(7);
+    let a = s23.new([[1i64, 2, 3], [4, 5, 6]]);
+    let b = s34.new([[1i64, 0, 0, 1], [0, 1, 0, 1], [0, 0, 1, 1]]);
+
+    let c = a.try_mul(&b).unwrap();
+    assert_eq!((c.nrows(), c.ncols()), (2, 4));
+    assert!(c == s23.new([[1i64, 2, 3, 6], [4, 5, 6, 15]]));
+    assert!(c.parent() == GenericMatSpace::init(&zz, 2u64, 4u64));
+    assert!(c.parent() != s23 && c.parent() != s34);
+    // the new space inherits the tuning of the left operand
+    assert_eq!(c.parent().block_size(), 7);
+    assert!(&a*&b == c);
+
+    // a product of the shape of an operand stays in its space
+    let id = GenericMatSpace::init(&zz, 3u64, 3u64).one();
+    let d = a.try_mul(&id).unwrap();
+    assert!(d == a);
+    d.parent().set_block_size(5);
+    assert_eq!(s23.block_size(), 5);
+}
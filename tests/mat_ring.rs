@@ -0,0 +1,110 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use inertia_algebra::*;
+use inertia_generic::mat::{GenericMat, GenericMatSpace};
+use inertia_generic::laurent::GenericLaurentPolyRing;
+use inertia_generic::poly::GenericPolyRing;
+use inertia_generic::prime_field::PrimeField;
+use inertia_generic::series::{GenericLazySeriesRing, GenericPowerSeriesRing};
+
+// the 4 by 4 matrix with the entries of the 2 by 2 blocks of m
+fn flatten(
+    fp: &PrimeField,
+    m: &GenericMat<GenericMatSpace<PrimeField>>
+) -> GenericMat<PrimeField> {
+    let mut entries = Vec::new();
+    for i in 0..4 {
+        for j in 0..4 {
+            entries.push(m.entry(i/2, j/2).entry(i % 2, j % 2).clone());
+        }
+    }
+    GenericMatSpace::init(fp, 4u64, 4u64).from_entries(entries)
+}
+
+#[test]
+fn matrix_polynomials() {
+    let fp = PrimeField::init(101);
+    let m2 = GenericMatSpace::init(&fp, 2u64, 2u64);
+    let r = GenericPolyRing::init(&m2, "x");
+    let a = m2.new([[1i64, 2], [3, 4]]);
+    let b = m2.new([[0i64, 1], [1, 0]]);
+
+    // (a + b*x)*(b + a*x) = a*b + (a^2 + b^2)*x + b*a*x^2
+    let f = r.new([&a, &b]);
+    let g = r.new([&b, &a]);
+    let h = &f*&g;
+    assert!(h.coeff(0) == Some(&(&a*&b)));
+    assert!(h.coeff(1) == Some(&(&(&a*&a) + &(&b*&b))));
+    assert!(h.coeff(2) == Some(&(&b*&a)));
+    assert!(&a*&b != &b*&a);
+    assert!(&f*&r.one() == f);
+}
+
+#[test]
+fn block_matrices() {
+    let fp = PrimeField::init(101);
+    let m2 = GenericMatSpace::init(&fp, 2u64, 2u64);
+    let blocks = GenericMatSpace::init(&m2, 2u64, 2u64);
+    let a = m2.new([[1i64, 2], [3, 4]]);
+    let b = m2.new([[0i64, 1], [1, 0]]);
+    let c = m2.new([[5i64, 0], [7, 1]]);
+
+    let x = blocks.new([[&a, &b], [&c, &a]]);
+    let y = blocks.new([[&b, &c], [&a, &b]]);
+    assert!(flatten(&fp, &(&x*&y)) == &flatten(&fp, &x)*&flatten(&fp, &y));
+    assert!(&x*&blocks.one() == x);
+}
+
+#[test]
+#[should_panic(expected = "not square")]
+fn polynomials_over_non_square_matrices() {
+    let fp = PrimeField::init(101);
+    let m = GenericMatSpace::init(&fp, 2u64, 3u64);
+    GenericPolyRing::init(&m, "x");
+}
+
+#[test]
+#[should_panic(expected = "not square")]
+fn blocks_of_non_square_matrices() {
+    let fp = PrimeField::init(101);
+    let m = GenericMatSpace::init(&fp, 2u64, 3u64);
+    GenericMatSpace::init(&m, 2u64, 2u64);
+}
+
+#[test]
+#[should_panic(expected = "not square")]
+fn series_over_non_square_matrices() {
+    let fp = PrimeField::init(101);
+    let m = GenericMatSpace::init(&fp, 2u64, 3u64);
+    GenericPowerSeriesRing::init(&m, "x", 10);
+}
+
+#[test]
+#[should_panic(expected = "not square")]
+fn lazy_series_over_non_square_matrices() {
+    let fp = PrimeField::init(101);
+    let m = GenericMatSpace::init(&fp, 2u64, 3u64);
+    GenericLazySeriesRing::init(&m, "x");
+}
+
+#[test]
+#[should_panic(expected = "not square")]
+fn laurent_polynomials_over_non_square_matrices() {
+    let fp = PrimeField::init(101);
+    let m = GenericMatSpace::init(&fp, 2u64, 3u64);
+    GenericLaurentPolyRing::init(&m, "x");
+}
@@ -0,0 +1,32 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::integer::{Integer, IntegerRing};
+use inertia_generic::poly::GenericPolyRing;
+
+#[test]
+fn mullow_with_empty_coefficients() {
+    let zz = IntegerRing::init();
+    let zx = GenericPolyRing::init(&zz, "x");
+    let e = zx.new(Vec::<Integer>::new());
+    let f = zx.new([1i64, 2, 3]);
+    assert!(e.mullow(&f, 2).is_zero());
+    assert!(f.mullow(&e, 2).is_zero());
+    assert!(e.mul_classical(&e).is_zero());
+    assert!(f.mullow(&f, 2) == zx.new([1i64, 4]));
+}
@@ -0,0 +1,108 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::prime_field::PrimeField;
+
+fn mulmod(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128)*(b as u128) % n as u128) as u64
+}
+
+fn powmod(a: u64, mut e: u64, n: u64) -> u64 {
+    let (mut a, mut res) = (a % n, 1 % n);
+    while e > 0 {
+        if e & 1 == 1 {
+            res = mulmod(res, a, n);
+        }
+        a = mulmod(a, a, n);
+        e >>= 1;
+    }
+    res
+}
+
+#[test]
+fn montgomery_arithmetic_matches_remainders() {
+    // the largest primes below 2^64 and 2^63, a Mersenne prime and an NTT prime
+    for p in [u64::MAX - 58, (1 << 63) - 25, (1 << 61) - 1, 998_244_353, 3] {
+        let k = PrimeField::init(p);
+        let samples = [0, 1, 2, p/2, p/2 + 1, p - 2, p - 1, 0x9e37_79b9_7f4a_7c15 % p];
+        for &a in samples.iter() {
+            for &b in samples.iter() {
+                let (x, y) = (k.new(a), k.new(b));
+                assert_eq!((&x*&y).value(), mulmod(a, b, p));
+                assert_eq!((&x + &y).value(), ((a as u128 + b as u128) % p as u128) as u64);
+                assert_eq!((&x - &y).value(), ((a as u128 + (p - b) as u128) % p as u128) as u64);
+            }
+            let x = k.new(a);
+            assert_eq!(x.pow(p - 3).value(), powmod(a, p - 3, p));
+            match x.inv() {
+                Some(y) => assert_eq!(mulmod(a, y.value(), p), 1),
+                None => assert_eq!(a, 0),
+            }
+        }
+    }
+}
+
+#[test]
+fn characteristic_two() {
+    let k = PrimeField::init(2);
+    let one = k.one();
+    assert!(&one + &one == k.zero());
+    assert!(&one*&one == one);
+    assert!(-&one == one);
+    assert!(one.inv().unwrap() == one);
+    assert!(k.zero().inv().is_none());
+    assert!(k.new(3u64) == one);
+    assert!(k.primitive_root() == one);
+    assert!(k.root_of_unity(1).unwrap() == one);
+    assert!(k.root_of_unity(2).is_none());
+}
+
+#[test]
+fn primitive_roots_and_roots_of_unity() {
+    // 998244353 = 119*2^23 + 1 has least primitive root 3
+    let p = 998_244_353;
+    let k = PrimeField::init(p);
+    assert_eq!(k.primitive_root().value(), 3);
+    // the second call reads the cached root
+    assert_eq!(k.primitive_root().value(), 3);
+
+    for n in [2, 7, 17, 1 << 23, p - 1] {
+        let w = k.root_of_unity(n).unwrap().value();
+        assert_eq!(powmod(w, n, p), 1);
+        for q in [2, 7, 17] {
+            if n % q == 0 {
+                assert_ne!(powmod(w, n/q, p), 1);
+            }
+        }
+    }
+    assert!(k.root_of_unity(3).is_none());
+    assert!(k.root_of_unity(0).is_none());
+
+    // the largest 64-bit prime, where p - 1 = 2^2*11*137*547*5594472617641
+    let p = u64::MAX - 58;
+    let k = PrimeField::init(p);
+    let g = k.primitive_root().value();
+    assert_eq!(g, 2);
+    for q in [2, 11, 137, 547, 5_594_472_617_641] {
+        assert_ne!(powmod(g, (p - 1)/q, p), 1);
+    }
+    assert_eq!(PrimeField::init((1 << 61) - 1).primitive_root().value(), 37);
+
+    let w = k.root_of_unity(4).unwrap().value();
+    assert_eq!(mulmod(w, w, p), p - 1);
+}
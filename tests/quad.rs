@@ -0,0 +1,81 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use inertia_algebra::*;
+use inertia_generic::integer::IntegerRing;
+use inertia_generic::quad::{GenericQuad, GenericQuadRing};
+use inertia_generic::traits::{EuclideanRing, ExactDivisionRing, GcdRing};
+
+fn abs_norm(a: &GenericQuad<IntegerRing>) -> i128 {
+    a.norm().value().abs()
+}
+
+#[test]
+fn integer_divrem_rounds_to_nearest() {
+    let zz = IntegerRing::init();
+    for a in -20i64..=20 {
+        for b in (-7i64..=7).filter(|&b| b != 0) {
+            let (a, b) = (zz.new(a), zz.new(b));
+            let (q, r) = zz.divrem(&a, &b).unwrap();
+            assert!(&q*&b + &r == a);
+            assert!(2*r.value().abs() <= b.value().abs());
+        }
+    }
+    assert!(zz.divrem(&zz.one(), &zz.zero()).is_none());
+}
+
+#[test]
+fn quad_divrem_decreases_norm() {
+    let zz = IntegerRing::init();
+    for d in [-2i64, -1, 2, 3] {
+        let k = GenericQuadRing::init(&zz, &zz.new(d));
+        assert_eq!(k.euclidean_discriminant(), Some(d));
+        for (x, y) in [(7i64, 3i64), (-11, 5), (4, -9), (0, 13), (23, -1)] {
+            for (u, v) in [(2i64, 1i64), (-1, 2), (3, 0), (1, -3), (0, 2)] {
+                let a = k.new((x, y));
+                let b = k.new((u, v));
+                let (q, r) = k.divrem(&a, &b).unwrap();
+                assert!(&q*&b + &r == a);
+                assert!(abs_norm(&r) < abs_norm(&b));
+            }
+        }
+        assert!(k.divrem(&k.one(), &k.zero()).is_none());
+    }
+}
+
+#[test]
+fn quad_gcd_is_canonical() {
+    let zz = IntegerRing::init();
+    for d in [-2i64, -1, 2, 3] {
+        let k = GenericQuadRing::init(&zz, &zz.new(d));
+        let c = k.new((3i64, 2i64));
+        let a = &k.new((5i64, -1i64))*&c;
+        let b = &k.new((1i64, 4i64))*&c;
+        let g = k.gcd(&a, &b);
+        assert!(k.divexact(&a, &g).is_some());
+        assert!(k.divexact(&b, &g).is_some());
+        assert!(k.divexact(&g, &c).is_some());
+        assert!(k.canonical_unit(&g) == k.one());
+
+        // associates have the same canonical representative
+        let u = if d > 0 { k.new((d - 1, 1i64)) } else { k.new((-1i64, 0i64)) };
+        let v = &u*&u*&u;
+        let a = k.divexact(&g, &k.canonical_unit(&g)).unwrap();
+        let b = &g*&v;
+        let b = k.divexact(&b, &k.canonical_unit(&b)).unwrap();
+        assert!(a == b);
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::integer::IntegerRing;
+use inertia_generic::poly::GenericPolyRing;
+use inertia_generic::quotient::GenericPolyQuotientRing;
+use inertia_generic::zmod::Zmod;
+
+#[test]
+fn norm_and_trace() {
+    let zz = IntegerRing::init();
+    let zx = GenericPolyRing::init(&zz, "x");
+
+    // in Z[sqrt(2)], N(3 + 5*sqrt(2)) = 9 - 50 and Tr = 6
+    let k = GenericPolyQuotientRing::init(&zx, &zx.new([-2i64, 0, 1]));
+    let a = k.new([3i64, 5]);
+    assert!(a.norm() == zz.new(-41i64));
+    assert!(a.trace() == zz.new(6i64));
+
+    // 1 + 2^(1/3) is a root of (t - 1)^3 - 2 = t^3 - 3*t^2 + 3*t - 3
+    let k = GenericPolyQuotientRing::init(&zx, &zx.new([-2i64, 0, 0, 1]));
+    let a = k.new([1i64, 1]);
+    assert!(a.charpoly() == zx.new([-3i64, 3, -3, 1]));
+    assert!(a.norm() == zz.new(3i64));
+    assert!(a.trace() == zz.new(3i64));
+}
+
+#[test]
+fn inverse_over_integers() {
+    let zz = IntegerRing::init();
+    let zx = GenericPolyRing::init(&zz, "x");
+    let k = GenericPolyQuotientRing::init(&zx, &zx.new([-2i64, 0, 1]));
+
+    // 1 + sqrt(2) has norm -1, 2 + sqrt(2) has norm 2
+    let a = k.new([1i64, 1]);
+    assert!(a.inv().unwrap() == k.new([-1i64, 1]));
+    assert!(k.new([2i64, 1]).inv().is_none());
+    assert!(!k.new([2i64, 1]).is_unit());
+}
+
+#[test]
+fn inverse_over_composite_modulus() {
+    // in (Z/6Z)[x]/(x^2 + 1), N(1 + 2*x) = 5 is a unit but 2 is not, so the
+    // Euclidean algorithm cannot divide by 2*x + 1
+    let r = Zmod::init(6u64);
+    let rx = GenericPolyRing::init(&r, "x");
+    let k = GenericPolyQuotientRing::init(&rx, &rx.new([1i64, 0, 1]));
+
+    let a = k.new([1i64, 2]);
+    let b = a.inv().unwrap();
+    assert!(b == k.new([5i64, 2]));
+    assert!(&a*&b == k.one());
+
+    // N(2*x) = 4 and N(3) = 9 are not units
+    assert!(k.new([0i64, 2]).inv().is_none());
+    assert!(!k.new([3i64]).is_unit());
+}
+
+#[test]
+fn inverse_over_prime_field() {
+    let r = Zmod::init(7u64);
+    let rx = GenericPolyRing::init(&r, "x");
+    let k = GenericPolyQuotientRing::init(&rx, &rx.new([1i64, 0, 1]));
+
+    let a = k.new([1i64, 1]);
+    assert!(a.inv().unwrap() == k.new([4i64, 3]));
+    assert!(k.zero().inv().is_none());
+}
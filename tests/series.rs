@@ -0,0 +1,98 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::frac::GenericFracField;
+use inertia_generic::integer::IntegerRing;
+use inertia_generic::series::{GenericLazySeriesRing, GenericPowerSeriesRing, SeriesError};
+
+#[test]
+fn exp_inverts_log() {
+    let zz = IntegerRing::init();
+    let qq = GenericFracField::init(&zz);
+    let qs = GenericPowerSeriesRing::init(&qq, "x", 8);
+
+    let f = qs.new([(1i64, 1i64), (1, 1)]);
+    let g = f.log().unwrap();
+    assert!(g.precision() == 8);
+    assert!(g.exp().unwrap() == f);
+
+    // log(1 + x) = x - x^2/2 + x^3/3 - ...
+    let h = qs.new([(0i64, 1i64), (1, 1), (-1, 2), (1, 3), (-1, 4)]);
+    assert!(g.truncated(5) == h.truncated(5));
+}
+
+#[test]
+fn inverse_and_square_root() {
+    let zz = IntegerRing::init();
+    let qq = GenericFracField::init(&zz);
+    let qs = GenericPowerSeriesRing::init(&qq, "x", 8);
+
+    let f = qs.new([(1i64, 1i64), (1, 1), (2, 1), (-1, 1)]);
+    assert!(&f.inv().unwrap()*&f == qs.one());
+    let r = f.sqrt().unwrap();
+    assert!(&r*&r == f);
+}
+
+#[test]
+fn sine_and_cosine() {
+    let zz = IntegerRing::init();
+    let qq = GenericFracField::init(&zz);
+    let qs = GenericPowerSeriesRing::init(&qq, "x", 8);
+
+    let f = qs.new([(0i64, 1i64), (1, 1), (3, 1)]);
+    let (s, c) = f.sin_cos().unwrap();
+    assert!(&(&s*&s) + &(&c*&c) == qs.one());
+
+    // sin(x) = x - x^3/6 + ...
+    let s = qs.gen().sin().unwrap();
+    assert!(s.truncated(4) == qs.new([(0i64, 1i64), (1, 1), (0, 1), (-1, 6)]).truncated(4));
+}
+
+#[test]
+fn series_errors() {
+    let zz = IntegerRing::init();
+    let qq = GenericFracField::init(&zz);
+    let qs = GenericPowerSeriesRing::init(&qq, "x", 8);
+    let x = qs.gen();
+    let two_plus_x = qs.new([(2i64, 1i64), (1, 1)]);
+
+    assert_eq!(qs.big_oh(0).inv().err(), Some(SeriesError::UnknownConstant));
+    assert_eq!(x.inv().err(), Some(SeriesError::NonUnitConstant));
+    assert_eq!(qs.one().exp().err(), Some(SeriesError::NonZeroConstant));
+    assert_eq!(qs.one().sin().err(), Some(SeriesError::NonZeroConstant));
+    assert_eq!(x.log().err(), Some(SeriesError::NonOneConstant));
+    assert_eq!(two_plus_x.sqrt().err(), Some(SeriesError::NonOneConstant));
+
+    // 1/2 is not an integer
+    let zs = GenericPowerSeriesRing::init(&zz, "x", 8);
+    let f = zs.new([1i64, 1]);
+    assert_eq!(f.integral().err(), Some(SeriesError::NonInvertibleInteger(2)));
+    assert_eq!(zs.new([2i64, 1]).inv().err(), Some(SeriesError::NonUnitConstant));
+}
+
+#[test]
+fn recursive_lazy_series() {
+    // f = 1 + x*f^2 generates the Catalan numbers
+    let zz = IntegerRing::init();
+    let ls = GenericLazySeriesRing::init(&zz, "x");
+    let x = ls.gen();
+    let f = ls.undefined();
+    f.define(&(&ls.one() + &(&x*&(&f*&f))));
+    assert!(f.to_poly(8) == ls.poly_ring().new([1i64, 1, 2, 5, 14, 42, 132, 429]));
+    assert!(f.coeff(10) == zz.new(16796i64));
+}
@@ -0,0 +1,120 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::mat::{GenericMatSpace, MatError};
+use inertia_generic::prime_field::PrimeField;
+use inertia_generic::zmod::Zmod;
+
+#[test]
+fn solve_underdetermined_system() {
+    let fp = PrimeField::init(7);
+    // the last row is the sum of the first two
+    let a = GenericMatSpace::init(&fp, 3u64, 4u64)
+        .new([[1i64, 2, 0, 3], [2, 4, 1, 1], [3, 6, 1, 4]]);
+    let s31 = GenericMatSpace::init(&fp, 3u64, 1u64);
+
+    let b = s31.new([[6i64], [1], [0]]);
+    let s = a.solve(&b).unwrap();
+    assert_eq!((s.particular.nrows(), s.particular.ncols()), (4, 1));
+    assert!(&a*&s.particular == b);
+    assert_eq!(s.kernel.ncols(), 2);
+    assert!(&a*&s.kernel == GenericMatSpace::init(&fp, 3u64, 2u64).zero());
+
+    // several right-hand sides at once
+    let b = GenericMatSpace::init(&fp, 3u64, 2u64).new([[6i64, 0], [1, 1], [0, 1]]);
+    let s = a.solve(&b).unwrap();
+    assert!(&a*&s.particular == b);
+
+    let b = s31.new([[1i64], [0], [0]]);
+    assert_eq!(a.solve(&b).err(), Some(MatError::Inconsistent));
+
+    let b = GenericMatSpace::init(&fp, 2u64, 1u64).zero();
+    assert_eq!(
+        a.solve(&b).err(),
+        Some(MatError::DimensionMismatch { lhs: (3, 4), rhs: (2, 1) })
+    );
+}
+
+#[test]
+fn solve_left_non_square_system() {
+    let fp = PrimeField::init(7);
+    // the third column is the sum of the first two
+    let a = GenericMatSpace::init(&fp, 4u64, 3u64)
+        .new([[1i64, 2, 3], [2, 4, 6], [0, 1, 1], [3, 1, 4]]);
+
+    let b = GenericMatSpace::init(&fp, 2u64, 3u64).new([[6i64, 1, 0], [1, 2, 3]]);
+    let s = a.solve_left(&b).unwrap();
+    assert_eq!((s.particular.nrows(), s.particular.ncols()), (2, 4));
+    assert!(&s.particular*&a == b);
+    assert_eq!((s.kernel.nrows(), s.kernel.ncols()), (2, 4));
+    assert!(&s.kernel*&a == GenericMatSpace::init(&fp, 2u64, 3u64).zero());
+
+    let b = GenericMatSpace::init(&fp, 1u64, 3u64).new([[1i64, 0, 0]]);
+    assert_eq!(a.solve_left(&b).err(), Some(MatError::Inconsistent));
+
+    let b = GenericMatSpace::init(&fp, 1u64, 4u64).zero();
+    assert_eq!(
+        a.solve_left(&b).err(),
+        Some(MatError::DimensionMismatch { lhs: (4, 3), rhs: (1, 4) })
+    );
+}
+
+#[test]
+fn inverse_errors() {
+    let fp = PrimeField::init(7);
+    let s22 = GenericMatSpace::init(&fp, 2u64, 2u64);
+
+    let a = s22.new([[1i64, 2], [3, 5]]);
+    let inv = a.inverse().unwrap();
+    assert!(&a*&inv == s22.one());
+    assert!(&inv*&a == s22.one());
+
+    let a = s22.new([[1i64, 2], [2, 4]]);
+    assert_eq!(a.inverse().err(), Some(MatError::Singular));
+
+    let a = GenericMatSpace::init(&fp, 2u64, 3u64).zero();
+    assert_eq!(a.inverse().err(), Some(MatError::NotSquare { nrows: 2, ncols: 3 }));
+}
+
+#[test]
+fn adjugate_over_composite_modulus() {
+    let r = Zmod::init(12u64);
+    let s33 = GenericMatSpace::init(&r, 3u64, 3u64);
+
+    // determinant 22 = 10 mod 12, a zero divisor
+    let a = s33.new([[1i64, 2, 3], [0, 4, 5], [1, 0, 6]]);
+    let adj = a.adjugate();
+    let d = s33.new([[10i64, 0, 0], [0, 10, 0], [0, 0, 10]]);
+    assert!(&a*&adj == d);
+    assert!(&adj*&a == d);
+    assert_eq!(a.inverse_adjugate().err(), Some(MatError::Singular));
+
+    let s22 = GenericMatSpace::init(&r, 2u64, 2u64);
+    let a = s22.new([[1i64, 2], [3, 4]]);
+    assert!(a.adjugate() == s22.new([[4i64, -2], [-3, 1]]));
+    assert_eq!(a.inverse_adjugate().err(), Some(MatError::Singular));
+
+    // determinant 7, a unit which is its own inverse
+    let a = s22.new([[2i64, 3], [1, 5]]);
+    let inv = a.inverse_adjugate().unwrap();
+    assert!(inv == s22.new([[11i64, 3], [5, 2]]));
+    assert!(&a*&inv == s22.one());
+
+    let a = GenericMatSpace::init(&r, 2u64, 3u64).zero();
+    assert_eq!(a.inverse_adjugate().err(), Some(MatError::NotSquare { nrows: 2, ncols: 3 }));
+}
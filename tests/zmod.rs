@@ -0,0 +1,95 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use inertia_algebra::*;
+use inertia_generic::zmod::Zmod;
+
+fn mulmod(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128)*(b as u128) % n as u128) as u64
+}
+
+fn powmod(a: u64, e: u64, n: u64) -> u64 {
+    (0..e).fold(1 % n, |acc, _| mulmod(acc, a, n))
+}
+
+// residues near zero, near n/2 and near n
+fn samples(n: u64) -> Vec<u64> {
+    let mut res = vec![0, 1, 2, 3, n/2 - 1, n/2, n/2 + 1, n - 3, n - 2, n - 1];
+    res.retain(|&a| a < n);
+    res
+}
+
+#[test]
+fn arithmetic_matches_remainders() {
+    // 2^64 - 1 is composite and 2^64 - 59 is prime
+    for n in [u64::MAX, u64::MAX - 58, 1 << 63, 1_000_000_007, 6] {
+        let r = Zmod::init(n);
+        for &a in samples(n).iter() {
+            for &b in samples(n).iter() {
+                let (x, y) = (r.new(a), r.new(b));
+                let sum = ((a as u128 + b as u128) % n as u128) as u64;
+                let diff = ((a as u128 + n as u128 - b as u128) % n as u128) as u64;
+                assert_eq!((&x + &y).value(), sum);
+                assert_eq!((&x - &y).value(), diff);
+                assert_eq!((&x*&y).value(), mulmod(a, b, n));
+            }
+            assert_eq!(r.new(a).pow(5).value(), powmod(a, 5, n));
+            assert_eq!((-r.new(a)).value(), (n - a) % n);
+        }
+    }
+}
+
+#[test]
+fn modulus_two() {
+    let r = Zmod::init(2u64);
+    let one = r.one();
+    assert!(&one + &one == r.zero());
+    assert!(&one*&one == one);
+    assert!(-&one == one);
+    assert!(one.inv().unwrap() == one);
+    assert!(r.zero().inv().is_none());
+    assert_eq!(r.new(-3i64).value(), 1);
+}
+
+#[test]
+fn inverses_of_units_and_non_units() {
+    let r = Zmod::init(12u64);
+    for a in 0..12u64 {
+        let x = r.new(a);
+        let coprime = [1, 5, 7, 11].contains(&a);
+        assert_eq!(x.is_unit(), coprime);
+        match x.inv() {
+            Some(y) => {
+                assert!(coprime);
+                assert_eq!(mulmod(a, y.value(), 12), 1);
+            },
+            None => assert!(!coprime),
+        }
+    }
+
+    // 2^64 - 1 = 3*5*17*257*641*65537*6700417
+    let n = u64::MAX;
+    let r = Zmod::init(n);
+    for a in [2, 7, n - 1, n - 2, 1 << 40] {
+        let y = r.new(a).inv().unwrap();
+        assert_eq!(mulmod(a, y.value(), n), 1);
+    }
+    for a in [3, 5, 641, 6700417, n - 3] {
+        assert!(r.new(a).inv().is_none());
+        assert!(!r.new(a).is_unit());
+    }
+}